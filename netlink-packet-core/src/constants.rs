@@ -35,3 +35,12 @@ pub const NLM_F_NONREC: u16 = 256;
 pub const NLM_F_CAPPED: u16 = 256;
 /// extended ACK TVLs were included
 pub const NLM_F_ACK_TLVS: u16 = 512;
+
+/// Error message string (string)
+pub const NLMSGERR_ATTR_MSG: u16 = 1;
+/// Offset of the invalid attribute in the original message, counting from the beginning of the
+/// header (u32)
+pub const NLMSGERR_ATTR_OFFS: u16 = 2;
+/// Arbitrary subsystem specific cookie to be used - in the success case - to identify a created
+/// object or operation or similar (binary)
+pub const NLMSGERR_ATTR_COOKIE: u16 = 3;
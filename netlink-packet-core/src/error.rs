@@ -1,5 +1,13 @@
-use crate::{DecodeError, Emitable, Field, Parseable, Rest};
+use crate::{
+    constants::*,
+    utils::{
+        nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+        parsers::{parse_string, parse_u32},
+    },
+    DecodeError, Emitable, Field, Parseable, ParseableParametrized, Rest,
+};
 use byteorder::{ByteOrder, NativeEndian};
+use failure::ResultExt;
 use std::mem::size_of;
 use std::{fmt, io};
 
@@ -76,18 +84,25 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> ErrorBuffer<T> {
 pub struct ErrorMessage {
     pub code: i32,
     pub header: Vec<u8>,
+    /// Extended ACK attributes, sent by the kernel when the `NETLINK_EXT_ACK` socket option is
+    /// set
+    pub ext_ack: Vec<ExtAckNla>,
 }
 
 pub type AckMessage = ErrorMessage;
 
 impl Emitable for ErrorMessage {
     fn buffer_len(&self) -> usize {
-        size_of::<i32>() + self.header.len()
+        size_of::<i32>() + self.header.len() + self.ext_ack.as_slice().buffer_len()
     }
     fn emit(&self, buffer: &mut [u8]) {
         let mut buffer = ErrorBuffer::new(buffer);
         buffer.set_code(self.code);
-        buffer.payload_mut().copy_from_slice(&self.header)
+        let payload = buffer.payload_mut();
+        payload[..self.header.len()].copy_from_slice(&self.header);
+        self.ext_ack
+            .as_slice()
+            .emit(&mut payload[self.header.len()..]);
     }
 }
 
@@ -104,11 +119,137 @@ impl<'buffer, T: AsRef<[u8]> + 'buffer> Parseable<ErrorBuffer<&'buffer T>> for E
         Ok(ErrorMessage {
             code: buf.code(),
             header: buf.payload().to_vec(),
+            ext_ack: vec![],
+        })
+    }
+}
+
+/// Parse an error message, given the flags of the netlink header that contains it. These flags
+/// tell whether the original message was capped (`NLM_F_CAPPED`), and whether extended ACK
+/// attributes follow it (`NLM_F_ACK_TLVS`).
+impl<'buffer, T: AsRef<[u8]> + 'buffer> ParseableParametrized<ErrorBuffer<&'buffer T>, u16>
+    for ErrorMessage
+{
+    fn parse_with_param(
+        buf: &ErrorBuffer<&'buffer T>,
+        flags: u16,
+    ) -> Result<ErrorMessage, DecodeError> {
+        let payload = buf.payload();
+        if flags & NLM_F_ACK_TLVS != NLM_F_ACK_TLVS {
+            return ErrorMessage::parse(buf);
+        }
+
+        // The original message is either just its header, or the whole message, whose length
+        // is given by the header.
+        let header_len = if flags & NLM_F_CAPPED == NLM_F_CAPPED {
+            ORIGINAL_HEADER_LEN
+        } else if payload.len() >= 4 {
+            let len = NativeEndian::read_u32(&payload[..4]) as usize;
+            (len + 3) & !3
+        } else {
+            payload.len()
+        };
+        if header_len > payload.len() {
+            return Err(format!(
+                "invalid ErrorBuffer: original message is {} bytes but payload is only {} bytes",
+                header_len,
+                payload.len()
+            )
+            .into());
+        }
+
+        let err = "invalid extended ACK attribute";
+        let mut ext_ack = vec![];
+        for nla in NlasIterator::new(&payload[header_len..]) {
+            let nla = nla.context(err)?;
+            ext_ack.push(ExtAckNla::parse(&nla).context(err)?);
+        }
+        Ok(ErrorMessage {
+            code: buf.code(),
+            header: payload[..header_len].to_vec(),
+            ext_ack,
+        })
+    }
+}
+
+/// Length of a netlink header, ie what remains of the original message when it is capped
+const ORIGINAL_HEADER_LEN: usize = 16;
+
+/// Extended ACK attribute (`NLMSGERR_ATTR_*`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ExtAckNla {
+    /// Human readable error message
+    Message(String),
+    /// Offset of the offending attribute in the original message
+    Offset(u32),
+    /// Subsystem specific cookie
+    Cookie(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl Nla for ExtAckNla {
+    fn value_len(&self) -> usize {
+        use self::ExtAckNla::*;
+        match *self {
+            Message(ref string) => string.len() + 1,
+            Offset(_) => 4,
+            Cookie(ref bytes) => bytes.len(),
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::ExtAckNla::*;
+        match *self {
+            Message(ref string) => {
+                buffer[..string.len()].copy_from_slice(string.as_bytes());
+                buffer[string.len()] = 0;
+            }
+            Offset(value) => NativeEndian::write_u32(buffer, value),
+            Cookie(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::ExtAckNla::*;
+        match *self {
+            Message(_) => NLMSGERR_ATTR_MSG,
+            Offset(_) => NLMSGERR_ATTR_OFFS,
+            Cookie(_) => NLMSGERR_ATTR_COOKIE,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for ExtAckNla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::ExtAckNla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NLMSGERR_ATTR_MSG => {
+                Message(parse_string(payload).context("invalid NLMSGERR_ATTR_MSG value")?)
+            }
+            NLMSGERR_ATTR_OFFS => {
+                Offset(parse_u32(payload).context("invalid NLMSGERR_ATTR_OFFS value")?)
+            }
+            NLMSGERR_ATTR_COOKIE => Cookie(payload.to_vec()),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
         })
     }
 }
 
 impl ErrorMessage {
+    /// Create an error message with the given code and header, without extended ACK
+    /// attributes. Prefer this over a struct literal, which breaks whenever a field is added.
+    pub fn new(code: i32, header: Vec<u8>) -> Self {
+        ErrorMessage {
+            code,
+            header,
+            ext_ack: vec![],
+        }
+    }
+
     /// According to [`netlink(7)`](https://linux.die.net/man/7/netlink)
     /// the `NLMSG_ERROR` return Negative errno or 0 for acknowledgements.
     ///
@@ -117,11 +258,32 @@ impl ErrorMessage {
     pub fn to_io(&self) -> io::Error {
         io::Error::from_raw_os_error(self.code.abs())
     }
+
+    /// Return the human readable error message from the extended ACK attributes, if any
+    pub fn ext_ack_message(&self) -> Option<&str> {
+        self.ext_ack.iter().find_map(|nla| match nla {
+            ExtAckNla::Message(ref message) => Some(message.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Return the offset of the offending attribute in the original message, if the kernel
+    /// reported one in the extended ACK attributes
+    pub fn ext_ack_offset(&self) -> Option<u32> {
+        self.ext_ack.iter().find_map(|nla| match nla {
+            ExtAckNla::Offset(offset) => Some(*offset),
+            _ => None,
+        })
+    }
 }
 
 impl fmt::Display for ErrorMessage {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(&self.to_io(), f)
+        fmt::Display::fmt(&self.to_io(), f)?;
+        if let Some(message) = self.ext_ack_message() {
+            write!(f, ": {}", message)?;
+        }
+        Ok(())
     }
 }
 
@@ -141,6 +303,7 @@ mod tests {
         let err_msg = ErrorMessage {
             code: -95,
             header: vec![],
+            ext_ack: vec![],
         };
 
         let to_io: io::Error = err_msg.to_io();
@@ -148,4 +311,46 @@ mod tests {
         assert_eq!(err_msg.to_string(), io_err.to_string());
         assert_eq!(to_io.raw_os_error(), io_err.raw_os_error());
     }
+
+    #[rustfmt::skip]
+    static EXT_ACK: [u8; 44] = [
+        0xea, 0xff, 0xff, 0xff, // code: -22 (EINVAL)
+        // original header (capped)
+        0x28, 0x00, 0x00, 0x00, // length: 40
+        0x10, 0x00, // message type: 16 (RTM_NEWLINK)
+        0x05, 0x00, // flags: NLM_F_REQUEST | NLM_F_ACK
+        0x01, 0x00, 0x00, 0x00, // sequence number: 1
+        0x00, 0x00, 0x00, 0x00, // port number: 0
+        // NLMSGERR_ATTR_MSG
+        0x0d, 0x00, // L = 13
+        0x01, 0x00, // T = 1
+        0x62, 0x61, 0x64, 0x20, 0x61, 0x74, 0x74, 0x72, 0x00, // "bad attr\0"
+        0x00, 0x00, 0x00, // padding
+        // NLMSGERR_ATTR_OFFS
+        0x08, 0x00, // L = 8
+        0x02, 0x00, // T = 2
+        0x20, 0x00, 0x00, 0x00, // V = 32
+    ];
+
+    #[test]
+    fn parse_ext_ack() {
+        let buf = ErrorBuffer::new_checked(&EXT_ACK).unwrap();
+        let err_msg = ErrorMessage::parse_with_param(&buf, NLM_F_CAPPED | NLM_F_ACK_TLVS).unwrap();
+        assert_eq!(err_msg.code, -22);
+        assert_eq!(err_msg.header, EXT_ACK[4..20].to_vec());
+        assert_eq!(
+            err_msg.ext_ack,
+            vec![
+                ExtAckNla::Message("bad attr".to_string()),
+                ExtAckNla::Offset(32),
+            ]
+        );
+        assert_eq!(err_msg.ext_ack_message(), Some("bad attr"));
+        assert_eq!(err_msg.ext_ack_offset(), Some(32));
+        assert!(err_msg.to_string().ends_with(": bad attr"));
+
+        let mut buf = vec![0; err_msg.buffer_len()];
+        err_msg.emit(&mut buf);
+        assert_eq!(&buf[..], &EXT_ACK[..]);
+    }
 }
@@ -5,6 +5,7 @@ use crate::{
     payload::{NLMSG_DONE, NLMSG_ERROR, NLMSG_NOOP, NLMSG_OVERRUN},
     AckMessage, DecodeError, Emitable, ErrorBuffer, ErrorMessage, NetlinkBuffer,
     NetlinkDeserializable, NetlinkHeader, NetlinkPayload, NetlinkSerializable, Parseable,
+    ParseableParametrized,
 };
 
 /// Represent a netlink message.
@@ -98,7 +99,8 @@ where
             NLMSG_ERROR => {
                 let buf =
                     ErrorBuffer::new_checked(&bytes).context("failed to parse NLMSG_ERROR")?;
-                let msg = ErrorMessage::parse(&buf).context("failed to parse NLMSG_ERROR")?;
+                let msg = ErrorMessage::parse_with_param(&buf, header.flags)
+                    .context("failed to parse NLMSG_ERROR")?;
                 if msg.code >= 0 {
                     Ack(msg as AckMessage)
                 } else {
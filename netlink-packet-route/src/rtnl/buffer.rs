@@ -4,7 +4,8 @@ use crate::{
     AddressHeader, AddressMessage, AddressMessageBuffer, DecodeError, LinkMessage,
     LinkMessageBuffer, NeighbourMessage, NeighbourMessageBuffer, NeighbourTableMessage,
     NeighbourTableMessageBuffer, NsidMessage, NsidMessageBuffer, RouteHeader, RouteMessage,
-    RouteMessageBuffer, RtnlMessage, RuleMessage, RuleMessageBuffer, TcMessage, TcMessageBuffer,
+    RouteMessageBuffer, RtnlMessage, RuleMessage, RuleMessageBuffer, StatsMessage,
+    StatsMessageBuffer, TcMessage, TcMessageBuffer,
 };
 use failure::ResultExt;
 
@@ -19,7 +20,8 @@ impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<RtnlMessageBuffer<&'a T>
         let message = match message_type {
 
             // Link messages
-            RTM_NEWLINK | RTM_GETLINK | RTM_DELLINK | RTM_SETLINK => {
+            RTM_NEWLINK | RTM_GETLINK | RTM_DELLINK | RTM_SETLINK
+                | RTM_NEWLINKPROP | RTM_DELLINKPROP | RTM_GETLINKPROP => {
                 let msg = match LinkMessageBuffer::new_checked(&buf.inner()) {
                     Ok(buf) => LinkMessage::parse(&buf).context("invalid link message")?,
                     // HACK: iproute2 sends invalid RTM_GETLINK message, where the header is
@@ -39,6 +41,9 @@ impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<RtnlMessageBuffer<&'a T>
                     RTM_GETLINK => GetLink(msg),
                     RTM_DELLINK => DelLink(msg),
                     RTM_SETLINK => SetLink(msg),
+                    RTM_NEWLINKPROP => NewLinkProp(msg),
+                    RTM_DELLINKPROP => DelLinkProp(msg),
+                    RTM_GETLINKPROP => GetLinkProp(msg),
                     _ => unreachable!(),
                 }
             }
@@ -167,6 +172,17 @@ impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<RtnlMessageBuffer<&'a T>
                 }
             }
 
+            // Stats messages
+            RTM_NEWSTATS | RTM_GETSTATS => {
+                let err = "invalid stats message";
+                let msg = StatsMessage::parse(&StatsMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?;
+                match message_type {
+                    RTM_NEWSTATS => NewStats(msg),
+                    RTM_GETSTATS => GetStats(msg),
+                    _ => unreachable!(),
+                }
+            }
+
             _ => return Err(format!("Unknown message type: {}", message_type).into()),
         };
         Ok(message)
@@ -53,6 +53,9 @@ pub const RTM_GETNSID: u16 = 90;
 pub const RTM_NEWSTATS: u16 = 92;
 pub const RTM_GETSTATS: u16 = 94;
 pub const RTM_NEWCACHEREPORT: u16 = 96;
+pub const RTM_NEWLINKPROP: u16 = 108;
+pub const RTM_DELLINKPROP: u16 = 109;
+pub const RTM_GETLINKPROP: u16 = 110;
 
 /// Unknown route
 pub const RTN_UNSPEC: u8 = 0;
@@ -262,6 +265,8 @@ pub const AF_PHONET: u16 = libc::AF_PHONET as u16;
 pub const AF_IEEE802154: u16 = libc::AF_IEEE802154 as u16;
 pub const AF_CAIF: u16 = libc::AF_CAIF as u16;
 pub const AF_ALG: u16 = libc::AF_ALG as u16;
+// libc only exposes AF_MPLS for some targets
+pub const AF_MPLS: u16 = 28;
 
 pub const NETNSA_NONE: u16 = 0;
 pub const NETNSA_NSID: u16 = 1;
@@ -432,6 +437,61 @@ pub const IFLA_BR_VLAN_STATS_ENABLED: u16 = 41;
 pub const IFLA_BR_MCAST_STATS_ENABLED: u16 = 42;
 pub const IFLA_BR_MCAST_IGMP_VERSION: u16 = 43;
 pub const IFLA_BR_MCAST_MLD_VERSION: u16 = 44;
+pub const IFLA_BRPORT_UNSPEC: u16 = 0;
+pub const IFLA_BRPORT_STATE: u16 = 1;
+pub const IFLA_BRPORT_PRIORITY: u16 = 2;
+pub const IFLA_BRPORT_COST: u16 = 3;
+pub const IFLA_BRPORT_MODE: u16 = 4;
+pub const IFLA_BRPORT_GUARD: u16 = 5;
+pub const IFLA_BRPORT_PROTECT: u16 = 6;
+pub const IFLA_BRPORT_FAST_LEAVE: u16 = 7;
+pub const IFLA_BRPORT_LEARNING: u16 = 8;
+pub const IFLA_BRPORT_UNICAST_FLOOD: u16 = 9;
+pub const IFLA_BRPORT_PROXYARP: u16 = 10;
+pub const IFLA_BRPORT_LEARNING_SYNC: u16 = 11;
+pub const IFLA_BRPORT_PROXYARP_WIFI: u16 = 12;
+pub const IFLA_BRPORT_ROOT_ID: u16 = 13;
+pub const IFLA_BRPORT_BRIDGE_ID: u16 = 14;
+pub const IFLA_BRPORT_DESIGNATED_PORT: u16 = 15;
+pub const IFLA_BRPORT_DESIGNATED_COST: u16 = 16;
+pub const IFLA_BRPORT_ID: u16 = 17;
+pub const IFLA_BRPORT_NO: u16 = 18;
+pub const IFLA_BRPORT_TOPOLOGY_CHANGE_ACK: u16 = 19;
+pub const IFLA_BRPORT_CONFIG_PENDING: u16 = 20;
+pub const IFLA_BRPORT_MESSAGE_AGE_TIMER: u16 = 21;
+pub const IFLA_BRPORT_FORWARD_DELAY_TIMER: u16 = 22;
+pub const IFLA_BRPORT_HOLD_TIMER: u16 = 23;
+pub const IFLA_BRPORT_FLUSH: u16 = 24;
+pub const IFLA_BRPORT_MULTICAST_ROUTER: u16 = 25;
+pub const IFLA_BRPORT_PAD: u16 = 26;
+pub const IFLA_BRPORT_MCAST_FLOOD: u16 = 27;
+pub const IFLA_BRPORT_MCAST_TO_UCAST: u16 = 28;
+pub const IFLA_BRPORT_VLAN_TUNNEL: u16 = 29;
+pub const IFLA_BRPORT_BCAST_FLOOD: u16 = 30;
+pub const IFLA_BRPORT_GROUP_FWD_MASK: u16 = 31;
+pub const IFLA_BRPORT_NEIGH_SUPPRESS: u16 = 32;
+pub const IFLA_BRPORT_ISOLATED: u16 = 33;
+pub const IFLA_BRPORT_BACKUP_PORT: u16 = 34;
+pub const BR_STATE_DISABLED: u8 = 0;
+pub const BR_STATE_LISTENING: u8 = 1;
+pub const BR_STATE_LEARNING: u8 = 2;
+pub const BR_STATE_FORWARDING: u8 = 3;
+pub const BR_STATE_BLOCKING: u8 = 4;
+pub const IFLA_BRIDGE_FLAGS: u16 = 0;
+pub const IFLA_BRIDGE_MODE: u16 = 1;
+pub const IFLA_BRIDGE_VLAN_INFO: u16 = 2;
+pub const IFLA_BRIDGE_VLAN_TUNNEL_INFO: u16 = 3;
+pub const BRIDGE_FLAGS_MASTER: u16 = 1;
+pub const BRIDGE_FLAGS_SELF: u16 = 2;
+pub const BRIDGE_MODE_VEB: u16 = 0;
+pub const BRIDGE_MODE_VEPA: u16 = 1;
+pub const BRIDGE_VLAN_INFO_MASTER: u16 = 1;
+pub const BRIDGE_VLAN_INFO_PVID: u16 = 2;
+pub const BRIDGE_VLAN_INFO_UNTAGGED: u16 = 4;
+pub const BRIDGE_VLAN_INFO_RANGE_BEGIN: u16 = 8;
+pub const BRIDGE_VLAN_INFO_RANGE_END: u16 = 16;
+pub const BRIDGE_VLAN_INFO_BRENTRY: u16 = 32;
+pub const BRIDGE_VLAN_INFO_ONLINK: u16 = 64;
 pub const IFLA_VLAN_UNSPEC: u16 = 0;
 pub const IFLA_VLAN_ID: u16 = 1;
 pub const IFLA_VLAN_FLAGS: u16 = 2;
@@ -440,6 +500,59 @@ pub const IFLA_VLAN_INGRESS_QOS: u16 = 4;
 pub const IFLA_VLAN_PROTOCOL: u16 = 5;
 pub const VETH_INFO_UNSPEC: u16 = 0;
 pub const VETH_INFO_PEER: u16 = 1;
+pub const IFLA_MACSEC_UNSPEC: u16 = 0;
+pub const IFLA_MACSEC_SCI: u16 = 1;
+pub const IFLA_MACSEC_PORT: u16 = 2;
+pub const IFLA_MACSEC_ICV_LEN: u16 = 3;
+pub const IFLA_MACSEC_CIPHER_SUITE: u16 = 4;
+pub const IFLA_MACSEC_WINDOW: u16 = 5;
+pub const IFLA_MACSEC_ENCODING_SA: u16 = 6;
+pub const IFLA_MACSEC_ENCRYPT: u16 = 7;
+pub const IFLA_MACSEC_PROTECT: u16 = 8;
+pub const IFLA_MACSEC_INC_SCI: u16 = 9;
+pub const IFLA_MACSEC_ES: u16 = 10;
+pub const IFLA_MACSEC_SCB: u16 = 11;
+pub const IFLA_MACSEC_REPLAY_PROTECT: u16 = 12;
+pub const IFLA_MACSEC_VALIDATION: u16 = 13;
+pub const IFLA_MACSEC_PAD: u16 = 14;
+pub const IFLA_MACSEC_OFFLOAD: u16 = 15;
+pub const MACSEC_CIPHER_ID_GCM_AES_128: u64 = 0x0080_C200_0100_0001;
+pub const MACSEC_CIPHER_ID_GCM_AES_256: u64 = 0x0080_C200_0100_0002;
+pub const MACSEC_CIPHER_ID_GCM_AES_XPN_128: u64 = 0x0080_C200_0100_0003;
+pub const MACSEC_CIPHER_ID_GCM_AES_XPN_256: u64 = 0x0080_C200_0100_0004;
+pub const MACSEC_DEFAULT_CIPHER_ID: u64 = 0x0080_0200_0100_0001;
+pub const MACSEC_VALIDATE_DISABLED: u8 = 0;
+pub const MACSEC_VALIDATE_CHECK: u8 = 1;
+pub const MACSEC_VALIDATE_STRICT: u8 = 2;
+pub const MACSEC_OFFLOAD_OFF: u8 = 0;
+pub const MACSEC_OFFLOAD_PHY: u8 = 1;
+pub const MACSEC_OFFLOAD_MAC: u8 = 2;
+pub const IFLA_GENEVE_UNSPEC: u16 = 0;
+pub const IFLA_GENEVE_ID: u16 = 1;
+pub const IFLA_GENEVE_REMOTE: u16 = 2;
+pub const IFLA_GENEVE_TTL: u16 = 3;
+pub const IFLA_GENEVE_TOS: u16 = 4;
+pub const IFLA_GENEVE_PORT: u16 = 5;
+pub const IFLA_GENEVE_COLLECT_METADATA: u16 = 6;
+pub const IFLA_GENEVE_REMOTE6: u16 = 7;
+pub const IFLA_GENEVE_UDP_CSUM: u16 = 8;
+pub const IFLA_GENEVE_UDP_ZERO_CSUM6_TX: u16 = 9;
+pub const IFLA_GENEVE_UDP_ZERO_CSUM6_RX: u16 = 10;
+pub const IFLA_GENEVE_LABEL: u16 = 11;
+pub const IFLA_GENEVE_TTL_INHERIT: u16 = 12;
+pub const IFLA_GENEVE_DF: u16 = 13;
+pub const IFLA_XFRM_UNSPEC: u16 = 0;
+pub const IFLA_XFRM_LINK: u16 = 1;
+pub const IFLA_XFRM_IF_ID: u16 = 2;
+pub const IFLA_XFRM_COLLECT_METADATA: u16 = 3;
+pub const IFLA_HSR_UNSPEC: u16 = 0;
+pub const IFLA_HSR_SLAVE1: u16 = 1;
+pub const IFLA_HSR_SLAVE2: u16 = 2;
+pub const IFLA_HSR_MULTICAST_SPEC: u16 = 3;
+pub const IFLA_HSR_SUPERVISION_ADDR: u16 = 4;
+pub const IFLA_HSR_SEQ_NR: u16 = 5;
+pub const IFLA_HSR_VERSION: u16 = 6;
+pub const IFLA_HSR_PROTOCOL: u16 = 7;
 
 pub const ARPHRD_NETROM: u16 = 0;
 pub const ARPHRD_ETHER: u16 = 1;
@@ -569,8 +682,95 @@ pub const IFLA_IF_NETNSID: u16 = 46;
 pub const IFLA_CARRIER_UP_COUNT: u16 = 47;
 pub const IFLA_CARRIER_DOWN_COUNT: u16 = 48;
 pub const IFLA_NEW_IFINDEX: u16 = 49;
+pub const IFLA_MIN_MTU: u16 = 50;
+pub const IFLA_MAX_MTU: u16 = 51;
+pub const IFLA_PROP_LIST: u16 = 52;
+pub const IFLA_ALT_IFNAME: u16 = 53;
+pub const IFLA_VF_INFO_UNSPEC: u16 = 0;
+pub const IFLA_VF_INFO: u16 = 1;
+pub const IFLA_VF_UNSPEC: u16 = 0;
+pub const IFLA_VF_MAC: u16 = 1;
+pub const IFLA_VF_VLAN: u16 = 2;
+pub const IFLA_VF_TX_RATE: u16 = 3;
+pub const IFLA_VF_SPOOFCHK: u16 = 4;
+pub const IFLA_VF_LINK_STATE: u16 = 5;
+pub const IFLA_VF_RATE: u16 = 6;
+pub const IFLA_VF_RSS_QUERY_EN: u16 = 7;
+pub const IFLA_VF_STATS: u16 = 8;
+pub const IFLA_VF_TRUST: u16 = 9;
+pub const IFLA_VF_IB_NODE_GUID: u16 = 10;
+pub const IFLA_VF_IB_PORT_GUID: u16 = 11;
+pub const IFLA_VF_VLAN_LIST: u16 = 12;
+pub const IFLA_VF_BROADCAST: u16 = 13;
+pub const IFLA_VF_STATS_RX_PACKETS: u16 = 0;
+pub const IFLA_VF_STATS_TX_PACKETS: u16 = 1;
+pub const IFLA_VF_STATS_RX_BYTES: u16 = 2;
+pub const IFLA_VF_STATS_TX_BYTES: u16 = 3;
+pub const IFLA_VF_STATS_BROADCAST: u16 = 4;
+pub const IFLA_VF_STATS_MULTICAST: u16 = 5;
+pub const IFLA_VF_STATS_PAD: u16 = 6;
+pub const IFLA_VF_STATS_RX_DROPPED: u16 = 7;
+pub const IFLA_VF_STATS_TX_DROPPED: u16 = 8;
+pub const IFLA_VF_LINK_STATE_AUTO: u32 = 0;
+pub const IFLA_VF_LINK_STATE_ENABLE: u32 = 1;
+pub const IFLA_VF_LINK_STATE_DISABLE: u32 = 2;
+pub const IFLA_XDP_UNSPEC: u16 = 0;
+pub const IFLA_XDP_FD: u16 = 1;
+pub const IFLA_XDP_ATTACHED: u16 = 2;
+pub const IFLA_XDP_FLAGS: u16 = 3;
+pub const IFLA_XDP_PROG_ID: u16 = 4;
+pub const IFLA_XDP_DRV_PROG_ID: u16 = 5;
+pub const IFLA_XDP_SKB_PROG_ID: u16 = 6;
+pub const IFLA_XDP_HW_PROG_ID: u16 = 7;
+pub const IFLA_XDP_EXPECTED_FD: u16 = 8;
+pub const XDP_FLAGS_UPDATE_IF_NOEXIST: u32 = 1;
+pub const XDP_FLAGS_SKB_MODE: u32 = 2;
+pub const XDP_FLAGS_DRV_MODE: u32 = 4;
+pub const XDP_FLAGS_HW_MODE: u32 = 8;
+pub const XDP_FLAGS_REPLACE: u32 = 16;
+pub const XDP_FLAGS_MODES: u32 = XDP_FLAGS_SKB_MODE | XDP_FLAGS_DRV_MODE | XDP_FLAGS_HW_MODE;
+pub const XDP_ATTACHED_NONE: u8 = 0;
+pub const XDP_ATTACHED_DRV: u8 = 1;
+pub const XDP_ATTACHED_SKB: u8 = 2;
+pub const XDP_ATTACHED_HW: u8 = 3;
+pub const XDP_ATTACHED_MULTI: u8 = 4;
+pub const RTEXT_FILTER_VF: u32 = 1;
+pub const RTEXT_FILTER_BRVLAN: u32 = 2;
+pub const RTEXT_FILTER_BRVLAN_COMPRESSED: u32 = 4;
+pub const RTEXT_FILTER_SKIP_STATS: u32 = 8;
 pub const IFLA_INET_UNSPEC: u16 = 0;
 pub const IFLA_INET_CONF: u16 = 1;
+pub const IPV4_DEVCONF_FORWARDING: u16 = 1;
+pub const IPV4_DEVCONF_MC_FORWARDING: u16 = 2;
+pub const IPV4_DEVCONF_PROXY_ARP: u16 = 3;
+pub const IPV4_DEVCONF_ACCEPT_REDIRECTS: u16 = 4;
+pub const IPV4_DEVCONF_SECURE_REDIRECTS: u16 = 5;
+pub const IPV4_DEVCONF_SEND_REDIRECTS: u16 = 6;
+pub const IPV4_DEVCONF_SHARED_MEDIA: u16 = 7;
+pub const IPV4_DEVCONF_RP_FILTER: u16 = 8;
+pub const IPV4_DEVCONF_ACCEPT_SOURCE_ROUTE: u16 = 9;
+pub const IPV4_DEVCONF_BOOTP_RELAY: u16 = 10;
+pub const IPV4_DEVCONF_LOG_MARTIANS: u16 = 11;
+pub const IPV4_DEVCONF_TAG: u16 = 12;
+pub const IPV4_DEVCONF_ARPFILTER: u16 = 13;
+pub const IPV4_DEVCONF_MEDIUM_ID: u16 = 14;
+pub const IPV4_DEVCONF_NOXFRM: u16 = 15;
+pub const IPV4_DEVCONF_NOPOLICY: u16 = 16;
+pub const IPV4_DEVCONF_FORCE_IGMP_VERSION: u16 = 17;
+pub const IPV4_DEVCONF_ARP_ANNOUNCE: u16 = 18;
+pub const IPV4_DEVCONF_ARP_IGNORE: u16 = 19;
+pub const IPV4_DEVCONF_PROMOTE_SECONDARIES: u16 = 20;
+pub const IPV4_DEVCONF_ARP_ACCEPT: u16 = 21;
+pub const IPV4_DEVCONF_ARP_NOTIFY: u16 = 22;
+pub const IPV4_DEVCONF_ACCEPT_LOCAL: u16 = 23;
+pub const IPV4_DEVCONF_SRC_VMARK: u16 = 24;
+pub const IPV4_DEVCONF_PROXY_ARP_PVLAN: u16 = 25;
+pub const IPV4_DEVCONF_ROUTE_LOCALNET: u16 = 26;
+pub const IPV4_DEVCONF_IGMPV2_UNSOLICITED_REPORT_INTERVAL: u16 = 27;
+pub const IPV4_DEVCONF_IGMPV3_UNSOLICITED_REPORT_INTERVAL: u16 = 28;
+pub const IPV4_DEVCONF_IGNORE_ROUTES_WITH_LINKDOWN: u16 = 29;
+pub const IPV4_DEVCONF_DROP_UNICAST_IN_L2_MULTICAST: u16 = 30;
+pub const IPV4_DEVCONF_DROP_GRATUITOUS_ARP: u16 = 31;
 pub const IFLA_INET6_UNSPEC: u16 = 0;
 pub const IFLA_INET6_FLAGS: u16 = 1;
 pub const IFLA_INET6_CONF: u16 = 2;
@@ -580,6 +780,33 @@ pub const IFLA_INET6_CACHEINFO: u16 = 5;
 pub const IFLA_INET6_ICMP6STATS: u16 = 6;
 pub const IFLA_INET6_TOKEN: u16 = 7;
 pub const IFLA_INET6_ADDR_GEN_MODE: u16 = 8;
+pub const IF_RS_SENT: u32 = 0x10;
+pub const IF_RA_RCVD: u32 = 0x20;
+pub const IF_RA_MANAGED: u32 = 0x40;
+pub const IF_RA_OTHERCONF: u32 = 0x80;
+pub const IF_READY: u32 = 0x8000_0000;
+pub const IN6_ADDR_GEN_MODE_EUI64: u8 = 0;
+pub const IN6_ADDR_GEN_MODE_NONE: u8 = 1;
+pub const IN6_ADDR_GEN_MODE_STABLE_PRIVACY: u8 = 2;
+pub const IN6_ADDR_GEN_MODE_RANDOM: u8 = 3;
+pub const IFLA_STATS_UNSPEC: u16 = 0;
+pub const IFLA_STATS_LINK_64: u16 = 1;
+pub const IFLA_STATS_LINK_XSTATS: u16 = 2;
+pub const IFLA_STATS_LINK_XSTATS_SLAVE: u16 = 3;
+pub const IFLA_STATS_LINK_OFFLOAD_XSTATS: u16 = 4;
+pub const IFLA_STATS_AF_SPEC: u16 = 5;
+pub const IFLA_STATS_FILTER_LINK_64: u32 = 1 << (IFLA_STATS_LINK_64 - 1);
+pub const IFLA_STATS_FILTER_LINK_XSTATS: u32 = 1 << (IFLA_STATS_LINK_XSTATS - 1);
+pub const IFLA_STATS_FILTER_LINK_XSTATS_SLAVE: u32 = 1 << (IFLA_STATS_LINK_XSTATS_SLAVE - 1);
+pub const IFLA_STATS_FILTER_LINK_OFFLOAD_XSTATS: u32 = 1 << (IFLA_STATS_LINK_OFFLOAD_XSTATS - 1);
+pub const IFLA_STATS_FILTER_AF_SPEC: u32 = 1 << (IFLA_STATS_AF_SPEC - 1);
+pub const LINK_XSTATS_TYPE_UNSPEC: u16 = 0;
+pub const LINK_XSTATS_TYPE_BRIDGE: u16 = 1;
+pub const LINK_XSTATS_TYPE_BOND: u16 = 2;
+pub const IFLA_OFFLOAD_XSTATS_UNSPEC: u16 = 0;
+pub const IFLA_OFFLOAD_XSTATS_CPU_HIT: u16 = 1;
+pub const MPLS_STATS_UNSPEC: u16 = 0;
+pub const MPLS_STATS_LINK: u16 = 1;
 
 /// Link is up (administratively).
 pub const IFF_UP: u32 = libc::IFF_UP as u32;
@@ -628,6 +855,18 @@ pub const IF_OPER_TESTING: u8 = 4;
 pub const IF_OPER_DORMANT: u8 = 5;
 pub const IF_OPER_UP: u8 = 6;
 
+pub const IF_LINK_MODE_DEFAULT: u8 = 0;
+pub const IF_LINK_MODE_DORMANT: u8 = 1;
+pub const IF_LINK_MODE_TESTING: u8 = 2;
+
+pub const IFLA_EVENT_NONE: u32 = 0;
+pub const IFLA_EVENT_REBOOT: u32 = 1;
+pub const IFLA_EVENT_FEATURES: u32 = 2;
+pub const IFLA_EVENT_BONDING_FAILOVER: u32 = 3;
+pub const IFLA_EVENT_NOTIFY_PEERS: u32 = 4;
+pub const IFLA_EVENT_IGMP_RESEND: u32 = 5;
+pub const IFLA_EVENT_BONDING_OPTIONS: u32 = 6;
+
 /// Neighbour cache entry type: unknown type
 pub const NDA_UNSPEC: u16 = 0;
 /// Neighbour cache entry type: entry for a network layer destination
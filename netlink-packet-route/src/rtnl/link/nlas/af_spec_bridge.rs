@@ -0,0 +1,92 @@
+use byteorder::{ByteOrder, NativeEndian};
+use failure::ResultExt;
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::parse_u16,
+    traits::Parseable,
+    DecodeError,
+};
+
+// https://elixir.bootlin.com/linux/latest/source/include/uapi/linux/if_bridge.h
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum AfSpecBridge {
+    Flags(u16),
+    Mode(u16),
+    VlanInfo(BridgeVlanInfo),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for AfSpecBridge {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::AfSpecBridge::*;
+        match *self {
+            Flags(_)
+                | Mode(_)
+                => 2,
+            VlanInfo(_) => 4,
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::AfSpecBridge::*;
+        match *self {
+            Flags(ref value)
+                | Mode(ref value)
+                => NativeEndian::write_u16(buffer, *value),
+            VlanInfo(ref info) => {
+                NativeEndian::write_u16(&mut buffer[..2], info.flags);
+                NativeEndian::write_u16(&mut buffer[2..4], info.vid);
+            }
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::AfSpecBridge::*;
+        match *self {
+            Flags(_) => IFLA_BRIDGE_FLAGS,
+            Mode(_) => IFLA_BRIDGE_MODE,
+            VlanInfo(_) => IFLA_BRIDGE_VLAN_INFO,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for AfSpecBridge {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::AfSpecBridge::*;
+
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_FLAGS => {
+                Flags(parse_u16(payload).context("invalid IFLA_BRIDGE_FLAGS value")?)
+            }
+            IFLA_BRIDGE_MODE => Mode(parse_u16(payload).context("invalid IFLA_BRIDGE_MODE value")?),
+            IFLA_BRIDGE_VLAN_INFO => {
+                if payload.len() != 4 {
+                    return Err(
+                        format!("invalid IFLA_BRIDGE_VLAN_INFO value: {:?}", payload).into(),
+                    );
+                }
+                VlanInfo(BridgeVlanInfo {
+                    flags: NativeEndian::read_u16(&payload[..2]),
+                    vid: NativeEndian::read_u16(&payload[2..4]),
+                })
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("Unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// A VLAN entry of a bridge or bridge port (`struct bridge_vlan_info`). `flags` is a combination
+/// of the `BRIDGE_VLAN_INFO_*` constants.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub struct BridgeVlanInfo {
+    pub flags: u16,
+    pub vid: u16,
+}
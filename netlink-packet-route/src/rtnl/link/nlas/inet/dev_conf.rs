@@ -1,4 +1,7 @@
+use byteorder::{ByteOrder, NativeEndian};
+
 use crate::{
+    nlas::Nla,
     traits::{Emitable, Parseable},
     DecodeError,
 };
@@ -152,3 +155,24 @@ impl Emitable for InetDevConf {
         buffer.set_drop_gratuitous_arp(self.drop_gratuitous_arp);
     }
 }
+
+/// A single IPv4 configuration value of a link. `index` is one of the `IPV4_DEVCONF_*` constants.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct InetDevConfValue {
+    pub index: u16,
+    pub value: u32,
+}
+
+impl Nla for InetDevConfValue {
+    fn value_len(&self) -> usize {
+        4
+    }
+
+    fn kind(&self) -> u16 {
+        self.index
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        NativeEndian::write_u32(buffer, self.value)
+    }
+}
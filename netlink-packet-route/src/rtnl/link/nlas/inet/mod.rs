@@ -3,7 +3,7 @@ use failure::ResultExt;
 use crate::{
     constants::{IFLA_INET_CONF, IFLA_INET_UNSPEC},
     nlas::{DefaultNla, Nla, NlaBuffer},
-    traits::Parseable,
+    traits::{Emitable, Parseable},
     DecodeError,
 };
 
@@ -13,6 +13,9 @@ pub use self::dev_conf::*;
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub enum Inet {
     DevConf(Vec<u8>),
+    /// Individual configuration values to change. The kernel reports the whole configuration
+    /// as `DevConf`, but expects the values to change as nested attributes in requests.
+    DevConfValues(Vec<InetDevConfValue>),
     Unspec(Vec<u8>),
     Other(DefaultNla),
 }
@@ -23,6 +26,7 @@ impl Nla for Inet {
         match *self {
             Unspec(ref bytes) => bytes.len(),
             DevConf(_) => DEV_CONF_LEN,
+            DevConfValues(ref nlas) => nlas.as_slice().buffer_len(),
             Other(ref nla) => nla.value_len(),
         }
     }
@@ -32,6 +36,7 @@ impl Nla for Inet {
         match *self {
             Unspec(ref bytes) => (&mut buffer[..bytes.len()]).copy_from_slice(bytes.as_slice()),
             DevConf(ref dev_conf) => buffer[..dev_conf.len()].copy_from_slice(dev_conf.as_slice()),
+            DevConfValues(ref nlas) => nlas.as_slice().emit(buffer),
             Other(ref nla) => nla.emit_value(buffer),
         }
     }
@@ -40,7 +45,7 @@ impl Nla for Inet {
         use self::Inet::*;
         match *self {
             Unspec(_) => IFLA_INET_UNSPEC,
-            DevConf(_) => IFLA_INET_CONF,
+            DevConf(_) | DevConfValues(_) => IFLA_INET_CONF,
             Other(ref nla) => nla.kind(),
         }
     }
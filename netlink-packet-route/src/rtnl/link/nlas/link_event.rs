@@ -0,0 +1,54 @@
+use crate::constants::*;
+
+/// Reason of a link change notification (`IFLA_EVENT`)
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum LinkEvent {
+    None,
+    /// The device is being rebooted
+    Reboot,
+    /// The offload features of the device changed
+    Features,
+    /// A bonding device failed over to another slave
+    BondingFailover,
+    /// The device asked its peers to be notified of its presence (gratuitous ARP, unsolicited
+    /// neighbour advertisement)
+    NotifyPeers,
+    /// The device asked for its IGMP reports to be resent
+    IgmpResend,
+    /// The options of a bonding device changed
+    BondingOptions,
+    /// Unrecognized value
+    Other(u32),
+}
+
+impl From<u32> for LinkEvent {
+    fn from(value: u32) -> Self {
+        use self::LinkEvent::*;
+        match value {
+            IFLA_EVENT_NONE => None,
+            IFLA_EVENT_REBOOT => Reboot,
+            IFLA_EVENT_FEATURES => Features,
+            IFLA_EVENT_BONDING_FAILOVER => BondingFailover,
+            IFLA_EVENT_NOTIFY_PEERS => NotifyPeers,
+            IFLA_EVENT_IGMP_RESEND => IgmpResend,
+            IFLA_EVENT_BONDING_OPTIONS => BondingOptions,
+            _ => Other(value),
+        }
+    }
+}
+
+impl From<LinkEvent> for u32 {
+    fn from(value: LinkEvent) -> Self {
+        use self::LinkEvent::*;
+        match value {
+            None => IFLA_EVENT_NONE,
+            Reboot => IFLA_EVENT_REBOOT,
+            Features => IFLA_EVENT_FEATURES,
+            BondingFailover => IFLA_EVENT_BONDING_FAILOVER,
+            NotifyPeers => IFLA_EVENT_NOTIFY_PEERS,
+            IgmpResend => IFLA_EVENT_IGMP_RESEND,
+            BondingOptions => IFLA_EVENT_BONDING_OPTIONS,
+            Other(other) => other,
+        }
+    }
+}
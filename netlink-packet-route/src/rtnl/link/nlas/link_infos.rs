@@ -1,12 +1,16 @@
 use crate::{
     constants::*,
     nlas::{DefaultNla, Nla, NlaBuffer, NlasIterator},
-    parsers::{parse_mac, parse_string, parse_u16, parse_u32, parse_u64, parse_u8},
+    parsers::{
+        parse_ipv6, parse_mac, parse_string, parse_u16, parse_u16_be, parse_u32, parse_u32_be,
+        parse_u64, parse_u64_be, parse_u8,
+    },
     traits::{Emitable, Parseable},
     DecodeError, LinkMessage, LinkMessageBuffer,
 };
-use byteorder::{ByteOrder, NativeEndian};
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
 use failure::ResultExt;
+use std::net::{Ipv4Addr, Ipv6Addr};
 
 const DUMMY: &str = "dummy";
 const IFB: &str = "ifb";
@@ -29,6 +33,17 @@ const IP6GRE: &str = "ip6gre";
 const VTI: &str = "vti";
 const VRF: &str = "vrf";
 const GTP: &str = "gtp";
+const WIREGUARD: &str = "wireguard";
+const MACSEC: &str = "macsec";
+const GENEVE: &str = "geneve";
+const BAREUDP: &str = "bareudp";
+const XFRM: &str = "xfrm";
+const HSR: &str = "hsr";
+const TEAM: &str = "team";
+const NETDEVSIM: &str = "netdevsim";
+const VCAN: &str = "vcan";
+const IPOIB: &str = "ipoib";
+const BATADV: &str = "batadv";
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Info {
@@ -36,8 +51,8 @@ pub enum Info {
     Xstats(Vec<u8>),
     Kind(InfoKind),
     Data(InfoData),
-    SlaveKind(Vec<u8>),
-    SlaveData(Vec<u8>),
+    SlaveKind(InfoSlaveKind),
+    SlaveData(InfoSlaveData),
 }
 
 impl Nla for Info {
@@ -47,11 +62,11 @@ impl Nla for Info {
         match self {
             Unspec(ref bytes)
                 | Xstats(ref bytes)
-                | SlaveKind(ref bytes)
-                | SlaveData(ref bytes)
                 => bytes.len(),
             Kind(ref nla) => nla.value_len(),
             Data(ref nla) => nla.value_len(),
+            SlaveKind(ref nla) => nla.value_len(),
+            SlaveData(ref nla) => nla.value_len(),
         }
     }
 
@@ -61,11 +76,11 @@ impl Nla for Info {
         match self {
             Unspec(ref bytes)
                 | Xstats(ref bytes)
-                | SlaveKind(ref bytes)
-                | SlaveData(ref bytes)
                 => buffer.copy_from_slice(bytes),
             Kind(ref nla) => nla.emit_value(buffer),
             Data(ref nla) => nla.emit_value(buffer),
+            SlaveKind(ref nla) => nla.emit_value(buffer),
+            SlaveData(ref nla) => nla.emit_value(buffer),
         }
     }
 
@@ -75,7 +90,7 @@ impl Nla for Info {
             Unspec(_) => IFLA_INFO_UNSPEC,
             Xstats(_) => IFLA_INFO_XSTATS,
             SlaveKind(_) => IFLA_INFO_SLAVE_KIND,
-            SlaveData(_) => IFLA_INFO_DATA,
+            SlaveData(_) => IFLA_INFO_SLAVE_DATA,
             Kind(_) => IFLA_INFO_KIND,
             Data(_) => IFLA_INFO_DATA,
         }
@@ -99,13 +114,43 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VecInfo {
         let mut res = Vec::new();
         let nlas = NlasIterator::new(buf.into_inner());
         let mut link_info_kind: Option<InfoKind> = None;
+        let mut link_info_slave_kind: Option<InfoSlaveKind> = None;
         for nla in nlas {
             let nla = nla?;
             match nla.kind() {
                 IFLA_INFO_UNSPEC => res.push(Info::Unspec(nla.value().to_vec())),
                 IFLA_INFO_XSTATS => res.push(Info::Xstats(nla.value().to_vec())),
-                IFLA_INFO_SLAVE_KIND => res.push(Info::SlaveKind(nla.value().to_vec())),
-                IFLA_INFO_SLAVE_DATA => res.push(Info::SlaveData(nla.value().to_vec())),
+                IFLA_INFO_SLAVE_KIND => {
+                    let parsed = InfoSlaveKind::parse(&nla)?;
+                    res.push(Info::SlaveKind(parsed.clone()));
+                    link_info_slave_kind = Some(parsed);
+                }
+                IFLA_INFO_SLAVE_DATA => {
+                    if let Some(link_info_slave_kind) = link_info_slave_kind {
+                        let payload = nla.value();
+                        let info_slave_data = match link_info_slave_kind {
+                            InfoSlaveKind::Bridge => {
+                                let mut v = Vec::new();
+                                let err = "failed to parse IFLA_INFO_SLAVE_DATA (IFLA_INFO_SLAVE_KIND is 'bridge')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoBridgePort::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoSlaveData::Bridge(v)
+                            }
+                            InfoSlaveKind::Bond => InfoSlaveData::Bond(payload.to_vec()),
+                            InfoSlaveKind::Other(_) => InfoSlaveData::Other(payload.to_vec()),
+                        };
+                        res.push(Info::SlaveData(info_slave_data));
+                    } else {
+                        return Err(
+                            "IFLA_INFO_SLAVE_DATA is not preceded by an IFLA_INFO_SLAVE_KIND"
+                                .into(),
+                        );
+                    }
+                    link_info_slave_kind = None;
+                }
                 IFLA_INFO_KIND => {
                     let parsed = InfoKind::parse(&nla)?;
                     res.push(Info::Kind(parsed.clone()));
@@ -162,6 +207,57 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VecInfo {
                             InfoKind::Vti => InfoData::Vti(payload.to_vec()),
                             InfoKind::Vrf => InfoData::Vrf(payload.to_vec()),
                             InfoKind::Gtp => InfoData::Gtp(payload.to_vec()),
+                            InfoKind::Wireguard => InfoData::Wireguard(payload.to_vec()),
+                            InfoKind::MacSec => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'macsec')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoMacSec::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::MacSec(v)
+                            }
+                            InfoKind::Geneve => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'geneve')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoGeneve::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::Geneve(v)
+                            }
+                            InfoKind::BareUdp => InfoData::BareUdp(payload.to_vec()),
+                            InfoKind::Xfrm => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'xfrm')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoXfrmTun::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::Xfrm(v)
+                            }
+                            InfoKind::Hsr => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'hsr')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoHsr::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::Hsr(v)
+                            }
+                            InfoKind::Team => InfoData::Team(payload.to_vec()),
+                            InfoKind::NetDevSim => InfoData::NetDevSim(payload.to_vec()),
+                            InfoKind::Vcan => InfoData::Vcan(payload.to_vec()),
+                            InfoKind::IpoIb => InfoData::IpoIb(payload.to_vec()),
+                            InfoKind::BatAdv => InfoData::BatAdv(payload.to_vec()),
                             InfoKind::Other(_) => InfoData::Other(payload.to_vec()),
                         };
                         res.push(Info::Data(info_data));
@@ -200,6 +296,17 @@ pub enum InfoData {
     Vti(Vec<u8>),
     Vrf(Vec<u8>),
     Gtp(Vec<u8>),
+    Wireguard(Vec<u8>),
+    MacSec(Vec<InfoMacSec>),
+    Geneve(Vec<InfoGeneve>),
+    BareUdp(Vec<u8>),
+    Xfrm(Vec<InfoXfrmTun>),
+    Hsr(Vec<InfoHsr>),
+    Team(Vec<u8>),
+    NetDevSim(Vec<u8>),
+    Vcan(Vec<u8>),
+    IpoIb(Vec<u8>),
+    BatAdv(Vec<u8>),
     Other(Vec<u8>),
 }
 
@@ -210,6 +317,10 @@ impl Nla for InfoData {
         match self {
             Bridge(ref nlas) => nlas.as_slice().buffer_len(),
             Vlan(ref nlas) =>  nlas.as_slice().buffer_len(),
+            MacSec(ref nlas) => nlas.as_slice().buffer_len(),
+            Geneve(ref nlas) => nlas.as_slice().buffer_len(),
+            Xfrm(ref nlas) => nlas.as_slice().buffer_len(),
+            Hsr(ref nlas) => nlas.as_slice().buffer_len(),
             Veth(ref msg) => msg.buffer_len(),
             Dummy(ref bytes)
                 | Tun(ref bytes)
@@ -229,6 +340,13 @@ impl Nla for InfoData {
                 | Vti(ref bytes)
                 | Vrf(ref bytes)
                 | Gtp(ref bytes)
+                | Wireguard(ref bytes)
+                | BareUdp(ref bytes)
+                | Team(ref bytes)
+                | NetDevSim(ref bytes)
+                | Vcan(ref bytes)
+                | IpoIb(ref bytes)
+                | BatAdv(ref bytes)
                 | Other(ref bytes)
                 => bytes.len(),
         }
@@ -240,6 +358,10 @@ impl Nla for InfoData {
         match self {
             Bridge(ref nlas) => nlas.as_slice().emit(buffer),
             Vlan(ref nlas) => nlas.as_slice().emit(buffer),
+            MacSec(ref nlas) => nlas.as_slice().emit(buffer),
+            Geneve(ref nlas) => nlas.as_slice().emit(buffer),
+            Xfrm(ref nlas) => nlas.as_slice().emit(buffer),
+            Hsr(ref nlas) => nlas.as_slice().emit(buffer),
             Veth(ref msg) => msg.emit(buffer),
             Dummy(ref bytes)
                 | Tun(ref bytes)
@@ -259,6 +381,13 @@ impl Nla for InfoData {
                 | Vti(ref bytes)
                 | Vrf(ref bytes)
                 | Gtp(ref bytes)
+                | Wireguard(ref bytes)
+                | BareUdp(ref bytes)
+                | Team(ref bytes)
+                | NetDevSim(ref bytes)
+                | Vcan(ref bytes)
+                | IpoIb(ref bytes)
+                | BatAdv(ref bytes)
                 | Other(ref bytes)
                 => buffer.copy_from_slice(bytes),
         }
@@ -292,6 +421,17 @@ pub enum InfoKind {
     Vti,
     Vrf,
     Gtp,
+    Wireguard,
+    MacSec,
+    Geneve,
+    BareUdp,
+    Xfrm,
+    Hsr,
+    Team,
+    NetDevSim,
+    Vcan,
+    IpoIb,
+    BatAdv,
     Other(String),
 }
 
@@ -320,6 +460,17 @@ impl Nla for InfoKind {
             Vti => VTI.len(),
             Vrf => VRF.len(),
             Gtp => GTP.len(),
+            Wireguard => WIREGUARD.len(),
+            MacSec => MACSEC.len(),
+            Geneve => GENEVE.len(),
+            BareUdp => BAREUDP.len(),
+            Xfrm => XFRM.len(),
+            Hsr => HSR.len(),
+            Team => TEAM.len(),
+            NetDevSim => NETDEVSIM.len(),
+            Vcan => VCAN.len(),
+            IpoIb => IPOIB.len(),
+            BatAdv => BATADV.len(),
             Other(ref s) => s.len(),
         };
         len + 1
@@ -349,6 +500,17 @@ impl Nla for InfoKind {
             Vti => VTI,
             Vrf => VRF,
             Gtp => GTP,
+            Wireguard => WIREGUARD,
+            MacSec => MACSEC,
+            Geneve => GENEVE,
+            BareUdp => BAREUDP,
+            Xfrm => XFRM,
+            Hsr => HSR,
+            Team => TEAM,
+            NetDevSim => NETDEVSIM,
+            Vcan => VCAN,
+            IpoIb => IPOIB,
+            BatAdv => BATADV,
             Other(ref s) => s.as_str(),
         };
         buffer[..s.len()].copy_from_slice(s.as_bytes());
@@ -391,11 +553,110 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoKind {
             VTI => Vti,
             VRF => Vrf,
             GTP => Gtp,
+            WIREGUARD => Wireguard,
+            MACSEC => MacSec,
+            GENEVE => Geneve,
+            BAREUDP => BareUdp,
+            XFRM => Xfrm,
+            HSR => Hsr,
+            TEAM => Team,
+            NETDEVSIM => NetDevSim,
+            VCAN => Vcan,
+            IPOIB => IpoIb,
+            BATADV => BatAdv,
             _ => Other(s),
         })
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoSlaveKind {
+    Bridge,
+    Bond,
+    Other(String),
+}
+
+impl Nla for InfoSlaveKind {
+    fn value_len(&self) -> usize {
+        use self::InfoSlaveKind::*;
+        let len = match *self {
+            Bridge => BRIDGE.len(),
+            Bond => BOND.len(),
+            Other(ref s) => s.len(),
+        };
+        len + 1
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoSlaveKind::*;
+        let s = match *self {
+            Bridge => BRIDGE,
+            Bond => BOND,
+            Other(ref s) => s.as_str(),
+        };
+        buffer[..s.len()].copy_from_slice(s.as_bytes());
+        buffer[s.len()] = 0;
+    }
+
+    fn kind(&self) -> u16 {
+        IFLA_INFO_SLAVE_KIND
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoSlaveKind {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<InfoSlaveKind, DecodeError> {
+        use self::InfoSlaveKind::*;
+        if buf.kind() != IFLA_INFO_SLAVE_KIND {
+            return Err(format!(
+                "failed to parse IFLA_INFO_SLAVE_KIND: NLA type is {}",
+                buf.kind()
+            )
+            .into());
+        }
+        let s = parse_string(buf.value()).context("invalid IFLA_INFO_SLAVE_KIND value")?;
+        Ok(match s.as_str() {
+            BRIDGE => Bridge,
+            BOND => Bond,
+            _ => Other(s),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoSlaveData {
+    Bridge(Vec<InfoBridgePort>),
+    Bond(Vec<u8>),
+    Other(Vec<u8>),
+}
+
+impl Nla for InfoSlaveData {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoSlaveData::*;
+        match self {
+            Bridge(ref nlas) => nlas.as_slice().buffer_len(),
+            Bond(ref bytes)
+                | Other(ref bytes)
+                => bytes.len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoSlaveData::*;
+        match self {
+            Bridge(ref nlas) => nlas.as_slice().emit(buffer),
+            Bond(ref bytes)
+                | Other(ref bytes)
+                => buffer.copy_from_slice(bytes),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        IFLA_INFO_SLAVE_DATA
+    }
+}
+
 // https://elixir.bootlin.com/linux/latest/source/net/8021q/vlan_netlink.c#L21
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum InfoVlan {
@@ -868,6 +1129,756 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoBridge {
     }
 }
 
+// https://elixir.bootlin.com/linux/latest/source/drivers/net/macsec.c
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoMacSec {
+    Unspec(Vec<u8>),
+    /// Secure channel identifier: the MAC address in the 6 most significant bytes, followed by
+    /// the port
+    Sci(u64),
+    Port(u16),
+    IcvLen(u8),
+    CipherSuite(u64),
+    Window(u32),
+    EncodingSa(u8),
+    Encrypt(u8),
+    Protect(u8),
+    IncSci(u8),
+    Es(u8),
+    Scb(u8),
+    ReplayProtect(u8),
+    Validation(u8),
+    Pad(Vec<u8>),
+    Offload(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoMacSec {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoMacSec::*;
+        match self {
+            Unspec(bytes)
+                | Pad(bytes)
+                => bytes.len(),
+            Sci(_)
+                | CipherSuite(_)
+                => 8,
+            Window(_) => 4,
+            Port(_) => 2,
+            IcvLen(_)
+                | EncodingSa(_)
+                | Encrypt(_)
+                | Protect(_)
+                | IncSci(_)
+                | Es(_)
+                | Scb(_)
+                | ReplayProtect(_)
+                | Validation(_)
+                | Offload(_)
+                => 1,
+            Other(nla)
+                => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoMacSec::*;
+        match self {
+            Unspec(ref bytes)
+                | Pad(ref bytes)
+                => buffer.copy_from_slice(bytes),
+            // the SCI is in network byte order
+            Sci(ref value) => BigEndian::write_u64(buffer, *value),
+            CipherSuite(ref value) => NativeEndian::write_u64(buffer, *value),
+            Window(ref value) => NativeEndian::write_u32(buffer, *value),
+            // the port is in network byte order
+            Port(ref value) => BigEndian::write_u16(buffer, *value),
+            IcvLen(ref value)
+                | EncodingSa(ref value)
+                | Encrypt(ref value)
+                | Protect(ref value)
+                | IncSci(ref value)
+                | Es(ref value)
+                | Scb(ref value)
+                | ReplayProtect(ref value)
+                | Validation(ref value)
+                | Offload(ref value)
+                => buffer[0] = *value,
+            Other(nla)
+                => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoMacSec::*;
+        match self {
+            Unspec(_) => IFLA_MACSEC_UNSPEC,
+            Sci(_) => IFLA_MACSEC_SCI,
+            Port(_) => IFLA_MACSEC_PORT,
+            IcvLen(_) => IFLA_MACSEC_ICV_LEN,
+            CipherSuite(_) => IFLA_MACSEC_CIPHER_SUITE,
+            Window(_) => IFLA_MACSEC_WINDOW,
+            EncodingSa(_) => IFLA_MACSEC_ENCODING_SA,
+            Encrypt(_) => IFLA_MACSEC_ENCRYPT,
+            Protect(_) => IFLA_MACSEC_PROTECT,
+            IncSci(_) => IFLA_MACSEC_INC_SCI,
+            Es(_) => IFLA_MACSEC_ES,
+            Scb(_) => IFLA_MACSEC_SCB,
+            ReplayProtect(_) => IFLA_MACSEC_REPLAY_PROTECT,
+            Validation(_) => IFLA_MACSEC_VALIDATION,
+            Pad(_) => IFLA_MACSEC_PAD,
+            Offload(_) => IFLA_MACSEC_OFFLOAD,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoMacSec {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoMacSec::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_MACSEC_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_MACSEC_SCI => Sci(parse_u64_be(payload).context("invalid IFLA_MACSEC_SCI value")?),
+            IFLA_MACSEC_PORT => {
+                Port(parse_u16_be(payload).context("invalid IFLA_MACSEC_PORT value")?)
+            }
+            IFLA_MACSEC_ICV_LEN => {
+                IcvLen(parse_u8(payload).context("invalid IFLA_MACSEC_ICV_LEN value")?)
+            }
+            IFLA_MACSEC_CIPHER_SUITE => {
+                CipherSuite(parse_u64(payload).context("invalid IFLA_MACSEC_CIPHER_SUITE value")?)
+            }
+            IFLA_MACSEC_WINDOW => {
+                Window(parse_u32(payload).context("invalid IFLA_MACSEC_WINDOW value")?)
+            }
+            IFLA_MACSEC_ENCODING_SA => {
+                EncodingSa(parse_u8(payload).context("invalid IFLA_MACSEC_ENCODING_SA value")?)
+            }
+            IFLA_MACSEC_ENCRYPT => {
+                Encrypt(parse_u8(payload).context("invalid IFLA_MACSEC_ENCRYPT value")?)
+            }
+            IFLA_MACSEC_PROTECT => {
+                Protect(parse_u8(payload).context("invalid IFLA_MACSEC_PROTECT value")?)
+            }
+            IFLA_MACSEC_INC_SCI => {
+                IncSci(parse_u8(payload).context("invalid IFLA_MACSEC_INC_SCI value")?)
+            }
+            IFLA_MACSEC_ES => Es(parse_u8(payload).context("invalid IFLA_MACSEC_ES value")?),
+            IFLA_MACSEC_SCB => Scb(parse_u8(payload).context("invalid IFLA_MACSEC_SCB value")?),
+            IFLA_MACSEC_REPLAY_PROTECT => ReplayProtect(
+                parse_u8(payload).context("invalid IFLA_MACSEC_REPLAY_PROTECT value")?,
+            ),
+            IFLA_MACSEC_VALIDATION => {
+                Validation(parse_u8(payload).context("invalid IFLA_MACSEC_VALIDATION value")?)
+            }
+            IFLA_MACSEC_PAD => Pad(payload.to_vec()),
+            IFLA_MACSEC_OFFLOAD => {
+                Offload(parse_u8(payload).context("invalid IFLA_MACSEC_OFFLOAD value")?)
+            }
+            _ => Other(
+                DefaultNla::parse(buf)
+                    .context("invalid link info macsec NLA value (unknown type)")?,
+            ),
+        })
+    }
+}
+
+// https://elixir.bootlin.com/linux/latest/source/drivers/net/geneve.c
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoGeneve {
+    Unspec(Vec<u8>),
+    Id(u32),
+    Remote(Ipv4Addr),
+    Remote6(Ipv6Addr),
+    Ttl(u8),
+    Tos(u8),
+    Port(u16),
+    CollectMetadata,
+    UdpCsum(u8),
+    UdpZeroCsum6Tx(u8),
+    UdpZeroCsum6Rx(u8),
+    Label(u32),
+    TtlInherit(u8),
+    Df(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoGeneve {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoGeneve::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            CollectMetadata => 0,
+            Remote6(_) => 16,
+            Id(_)
+                | Remote(_)
+                | Label(_)
+                => 4,
+            Port(_) => 2,
+            Ttl(_)
+                | Tos(_)
+                | UdpCsum(_)
+                | UdpZeroCsum6Tx(_)
+                | UdpZeroCsum6Rx(_)
+                | TtlInherit(_)
+                | Df(_)
+                => 1,
+            Other(nla)
+                => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoGeneve::*;
+        match self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes),
+            CollectMetadata => {}
+            Id(ref value) => NativeEndian::write_u32(buffer, *value),
+            Remote(ref addr) => buffer.copy_from_slice(&addr.octets()[..]),
+            Remote6(ref addr) => buffer.copy_from_slice(&addr.octets()[..]),
+            // the port and the flow label are in network byte order
+            Port(ref value) => BigEndian::write_u16(buffer, *value),
+            Label(ref value) => BigEndian::write_u32(buffer, *value),
+            Ttl(ref value)
+                | Tos(ref value)
+                | UdpCsum(ref value)
+                | UdpZeroCsum6Tx(ref value)
+                | UdpZeroCsum6Rx(ref value)
+                | TtlInherit(ref value)
+                | Df(ref value)
+                => buffer[0] = *value,
+            Other(nla)
+                => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoGeneve::*;
+        match self {
+            Unspec(_) => IFLA_GENEVE_UNSPEC,
+            Id(_) => IFLA_GENEVE_ID,
+            Remote(_) => IFLA_GENEVE_REMOTE,
+            Remote6(_) => IFLA_GENEVE_REMOTE6,
+            Ttl(_) => IFLA_GENEVE_TTL,
+            Tos(_) => IFLA_GENEVE_TOS,
+            Port(_) => IFLA_GENEVE_PORT,
+            CollectMetadata => IFLA_GENEVE_COLLECT_METADATA,
+            UdpCsum(_) => IFLA_GENEVE_UDP_CSUM,
+            UdpZeroCsum6Tx(_) => IFLA_GENEVE_UDP_ZERO_CSUM6_TX,
+            UdpZeroCsum6Rx(_) => IFLA_GENEVE_UDP_ZERO_CSUM6_RX,
+            Label(_) => IFLA_GENEVE_LABEL,
+            TtlInherit(_) => IFLA_GENEVE_TTL_INHERIT,
+            Df(_) => IFLA_GENEVE_DF,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoGeneve {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoGeneve::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_GENEVE_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_GENEVE_ID => Id(parse_u32(payload).context("invalid IFLA_GENEVE_ID value")?),
+            IFLA_GENEVE_REMOTE => Remote(Ipv4Addr::from(
+                parse_u32_be(payload).context("invalid IFLA_GENEVE_REMOTE value")?,
+            )),
+            IFLA_GENEVE_REMOTE6 => Remote6(Ipv6Addr::from(
+                parse_ipv6(payload).context("invalid IFLA_GENEVE_REMOTE6 value")?,
+            )),
+            IFLA_GENEVE_TTL => Ttl(parse_u8(payload).context("invalid IFLA_GENEVE_TTL value")?),
+            IFLA_GENEVE_TOS => Tos(parse_u8(payload).context("invalid IFLA_GENEVE_TOS value")?),
+            IFLA_GENEVE_PORT => {
+                Port(parse_u16_be(payload).context("invalid IFLA_GENEVE_PORT value")?)
+            }
+            IFLA_GENEVE_COLLECT_METADATA => CollectMetadata,
+            IFLA_GENEVE_UDP_CSUM => {
+                UdpCsum(parse_u8(payload).context("invalid IFLA_GENEVE_UDP_CSUM value")?)
+            }
+            IFLA_GENEVE_UDP_ZERO_CSUM6_TX => UdpZeroCsum6Tx(
+                parse_u8(payload).context("invalid IFLA_GENEVE_UDP_ZERO_CSUM6_TX value")?,
+            ),
+            IFLA_GENEVE_UDP_ZERO_CSUM6_RX => UdpZeroCsum6Rx(
+                parse_u8(payload).context("invalid IFLA_GENEVE_UDP_ZERO_CSUM6_RX value")?,
+            ),
+            IFLA_GENEVE_LABEL => {
+                Label(parse_u32_be(payload).context("invalid IFLA_GENEVE_LABEL value")?)
+            }
+            IFLA_GENEVE_TTL_INHERIT => {
+                TtlInherit(parse_u8(payload).context("invalid IFLA_GENEVE_TTL_INHERIT value")?)
+            }
+            IFLA_GENEVE_DF => Df(parse_u8(payload).context("invalid IFLA_GENEVE_DF value")?),
+            _ => Other(
+                DefaultNla::parse(buf)
+                    .context("invalid link info geneve NLA value (unknown type)")?,
+            ),
+        })
+    }
+}
+
+// https://elixir.bootlin.com/linux/latest/source/net/xfrm/xfrm_interface.c
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoXfrmTun {
+    Unspec(Vec<u8>),
+    Link(u32),
+    IfId(u32),
+    CollectMetadata,
+    Other(DefaultNla),
+}
+
+impl Nla for InfoXfrmTun {
+    fn value_len(&self) -> usize {
+        use self::InfoXfrmTun::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            Link(_) | IfId(_) => 4,
+            CollectMetadata => 0,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoXfrmTun::*;
+        match self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes),
+            Link(ref value) | IfId(ref value) => NativeEndian::write_u32(buffer, *value),
+            CollectMetadata => {}
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoXfrmTun::*;
+        match self {
+            Unspec(_) => IFLA_XFRM_UNSPEC,
+            Link(_) => IFLA_XFRM_LINK,
+            IfId(_) => IFLA_XFRM_IF_ID,
+            CollectMetadata => IFLA_XFRM_COLLECT_METADATA,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoXfrmTun {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoXfrmTun::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_XFRM_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_XFRM_LINK => Link(parse_u32(payload).context("invalid IFLA_XFRM_LINK value")?),
+            IFLA_XFRM_IF_ID => IfId(parse_u32(payload).context("invalid IFLA_XFRM_IF_ID value")?),
+            IFLA_XFRM_COLLECT_METADATA => CollectMetadata,
+            _ => Other(
+                DefaultNla::parse(buf)
+                    .context("invalid link info xfrm NLA value (unknown type)")?,
+            ),
+        })
+    }
+}
+
+// https://elixir.bootlin.com/linux/latest/source/net/hsr/hsr_netlink.c
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoHsr {
+    Unspec(Vec<u8>),
+    Slave1(u32),
+    Slave2(u32),
+    MulticastSpec(u8),
+    SupervisionAddr([u8; 6]),
+    SeqNr(u16),
+    Version(u8),
+    Protocol(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoHsr {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoHsr::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            SupervisionAddr(_) => 6,
+            Slave1(_)
+                | Slave2(_)
+                => 4,
+            SeqNr(_) => 2,
+            MulticastSpec(_)
+                | Version(_)
+                | Protocol(_)
+                => 1,
+            Other(nla)
+                => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoHsr::*;
+        match self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes),
+            SupervisionAddr(ref value) => buffer.copy_from_slice(&value[..]),
+            Slave1(ref value)
+                | Slave2(ref value)
+                => NativeEndian::write_u32(buffer, *value),
+            SeqNr(ref value) => NativeEndian::write_u16(buffer, *value),
+            MulticastSpec(ref value)
+                | Version(ref value)
+                | Protocol(ref value)
+                => buffer[0] = *value,
+            Other(nla)
+                => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoHsr::*;
+        match self {
+            Unspec(_) => IFLA_HSR_UNSPEC,
+            Slave1(_) => IFLA_HSR_SLAVE1,
+            Slave2(_) => IFLA_HSR_SLAVE2,
+            MulticastSpec(_) => IFLA_HSR_MULTICAST_SPEC,
+            SupervisionAddr(_) => IFLA_HSR_SUPERVISION_ADDR,
+            SeqNr(_) => IFLA_HSR_SEQ_NR,
+            Version(_) => IFLA_HSR_VERSION,
+            Protocol(_) => IFLA_HSR_PROTOCOL,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoHsr {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoHsr::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_HSR_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_HSR_SLAVE1 => Slave1(parse_u32(payload).context("invalid IFLA_HSR_SLAVE1 value")?),
+            IFLA_HSR_SLAVE2 => Slave2(parse_u32(payload).context("invalid IFLA_HSR_SLAVE2 value")?),
+            IFLA_HSR_MULTICAST_SPEC => {
+                MulticastSpec(parse_u8(payload).context("invalid IFLA_HSR_MULTICAST_SPEC value")?)
+            }
+            IFLA_HSR_SUPERVISION_ADDR => SupervisionAddr(
+                parse_mac(payload).context("invalid IFLA_HSR_SUPERVISION_ADDR value")?,
+            ),
+            IFLA_HSR_SEQ_NR => SeqNr(parse_u16(payload).context("invalid IFLA_HSR_SEQ_NR value")?),
+            IFLA_HSR_VERSION => {
+                Version(parse_u8(payload).context("invalid IFLA_HSR_VERSION value")?)
+            }
+            IFLA_HSR_PROTOCOL => {
+                Protocol(parse_u8(payload).context("invalid IFLA_HSR_PROTOCOL value")?)
+            }
+            _ => Other(
+                DefaultNla::parse(buf).context("invalid link info hsr NLA value (unknown type)")?,
+            ),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoBridgePort {
+    Unspec(Vec<u8>),
+    State(u8),
+    Priority(u16),
+    Cost(u32),
+    Mode(u8),
+    Guard(u8),
+    Protect(u8),
+    FastLeave(u8),
+    Learning(u8),
+    UnicastFlood(u8),
+    ProxyArp(u8),
+    LearningSync(u8),
+    ProxyArpWifi(u8),
+    RootId((u16, [u8; 6])),
+    BridgeId((u16, [u8; 6])),
+    DesignatedPort(u16),
+    DesignatedCost(u16),
+    Id(u16),
+    No(u16),
+    TopologyChangeAck(u8),
+    ConfigPending(u8),
+    MessageAgeTimer(u64),
+    ForwardDelayTimer(u64),
+    HoldTimer(u64),
+    Flush,
+    MulticastRouter(u8),
+    Pad(Vec<u8>),
+    MulticastFlood(u8),
+    MulticastToUnicast(u8),
+    VlanTunnel(u8),
+    BroadcastFlood(u8),
+    GroupFwdMask(u16),
+    NeighSuppress(u8),
+    Isolated(u8),
+    BackupPort(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoBridgePort {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoBridgePort::*;
+        match self {
+            Unspec(bytes)
+                | Pad(bytes)
+                => bytes.len(),
+            Flush => 0,
+            MessageAgeTimer(_)
+                | ForwardDelayTimer(_)
+                | HoldTimer(_)
+                => 8,
+            RootId(_)
+                | BridgeId(_)
+                => 8,
+            Cost(_)
+                | BackupPort(_)
+                => 4,
+            Priority(_)
+                | DesignatedPort(_)
+                | DesignatedCost(_)
+                | Id(_)
+                | No(_)
+                | GroupFwdMask(_)
+                => 2,
+            State(_)
+                | Mode(_)
+                | Guard(_)
+                | Protect(_)
+                | FastLeave(_)
+                | Learning(_)
+                | UnicastFlood(_)
+                | ProxyArp(_)
+                | LearningSync(_)
+                | ProxyArpWifi(_)
+                | TopologyChangeAck(_)
+                | ConfigPending(_)
+                | MulticastRouter(_)
+                | MulticastFlood(_)
+                | MulticastToUnicast(_)
+                | VlanTunnel(_)
+                | BroadcastFlood(_)
+                | NeighSuppress(_)
+                | Isolated(_)
+                => 1,
+            Other(nla)
+                => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoBridgePort::*;
+        match self {
+            Unspec(ref bytes)
+                | Pad(ref bytes)
+                => buffer.copy_from_slice(bytes),
+            Flush => {}
+            MessageAgeTimer(ref value)
+                | ForwardDelayTimer(ref value)
+                | HoldTimer(ref value)
+                => NativeEndian::write_u64(buffer, *value),
+            Cost(ref value)
+                | BackupPort(ref value)
+                => NativeEndian::write_u32(buffer, *value),
+            Priority(ref value)
+                | DesignatedPort(ref value)
+                | DesignatedCost(ref value)
+                | Id(ref value)
+                | No(ref value)
+                | GroupFwdMask(ref value)
+                => NativeEndian::write_u16(buffer, *value),
+            RootId((ref priority, ref address))
+                | BridgeId((ref priority, ref address))
+                => {
+                    NativeEndian::write_u16(buffer, *priority);
+                    buffer[2..].copy_from_slice(&address[..]);
+                }
+            State(ref value)
+                | Mode(ref value)
+                | Guard(ref value)
+                | Protect(ref value)
+                | FastLeave(ref value)
+                | Learning(ref value)
+                | UnicastFlood(ref value)
+                | ProxyArp(ref value)
+                | LearningSync(ref value)
+                | ProxyArpWifi(ref value)
+                | TopologyChangeAck(ref value)
+                | ConfigPending(ref value)
+                | MulticastRouter(ref value)
+                | MulticastFlood(ref value)
+                | MulticastToUnicast(ref value)
+                | VlanTunnel(ref value)
+                | BroadcastFlood(ref value)
+                | NeighSuppress(ref value)
+                | Isolated(ref value)
+                => buffer[0] = *value,
+            Other(nla)
+                => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoBridgePort::*;
+        match self {
+            Unspec(_) => IFLA_BRPORT_UNSPEC,
+            State(_) => IFLA_BRPORT_STATE,
+            Priority(_) => IFLA_BRPORT_PRIORITY,
+            Cost(_) => IFLA_BRPORT_COST,
+            Mode(_) => IFLA_BRPORT_MODE,
+            Guard(_) => IFLA_BRPORT_GUARD,
+            Protect(_) => IFLA_BRPORT_PROTECT,
+            FastLeave(_) => IFLA_BRPORT_FAST_LEAVE,
+            Learning(_) => IFLA_BRPORT_LEARNING,
+            UnicastFlood(_) => IFLA_BRPORT_UNICAST_FLOOD,
+            ProxyArp(_) => IFLA_BRPORT_PROXYARP,
+            LearningSync(_) => IFLA_BRPORT_LEARNING_SYNC,
+            ProxyArpWifi(_) => IFLA_BRPORT_PROXYARP_WIFI,
+            RootId(_) => IFLA_BRPORT_ROOT_ID,
+            BridgeId(_) => IFLA_BRPORT_BRIDGE_ID,
+            DesignatedPort(_) => IFLA_BRPORT_DESIGNATED_PORT,
+            DesignatedCost(_) => IFLA_BRPORT_DESIGNATED_COST,
+            Id(_) => IFLA_BRPORT_ID,
+            No(_) => IFLA_BRPORT_NO,
+            TopologyChangeAck(_) => IFLA_BRPORT_TOPOLOGY_CHANGE_ACK,
+            ConfigPending(_) => IFLA_BRPORT_CONFIG_PENDING,
+            MessageAgeTimer(_) => IFLA_BRPORT_MESSAGE_AGE_TIMER,
+            ForwardDelayTimer(_) => IFLA_BRPORT_FORWARD_DELAY_TIMER,
+            HoldTimer(_) => IFLA_BRPORT_HOLD_TIMER,
+            Flush => IFLA_BRPORT_FLUSH,
+            MulticastRouter(_) => IFLA_BRPORT_MULTICAST_ROUTER,
+            Pad(_) => IFLA_BRPORT_PAD,
+            MulticastFlood(_) => IFLA_BRPORT_MCAST_FLOOD,
+            MulticastToUnicast(_) => IFLA_BRPORT_MCAST_TO_UCAST,
+            VlanTunnel(_) => IFLA_BRPORT_VLAN_TUNNEL,
+            BroadcastFlood(_) => IFLA_BRPORT_BCAST_FLOOD,
+            GroupFwdMask(_) => IFLA_BRPORT_GROUP_FWD_MASK,
+            NeighSuppress(_) => IFLA_BRPORT_NEIGH_SUPPRESS,
+            Isolated(_) => IFLA_BRPORT_ISOLATED,
+            BackupPort(_) => IFLA_BRPORT_BACKUP_PORT,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoBridgePort {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoBridgePort::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRPORT_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_BRPORT_STATE => {
+                State(parse_u8(payload).context("invalid IFLA_BRPORT_STATE value")?)
+            }
+            IFLA_BRPORT_PRIORITY => {
+                Priority(parse_u16(payload).context("invalid IFLA_BRPORT_PRIORITY value")?)
+            }
+            IFLA_BRPORT_COST => Cost(parse_u32(payload).context("invalid IFLA_BRPORT_COST value")?),
+            IFLA_BRPORT_MODE => Mode(parse_u8(payload).context("invalid IFLA_BRPORT_MODE value")?),
+            IFLA_BRPORT_GUARD => {
+                Guard(parse_u8(payload).context("invalid IFLA_BRPORT_GUARD value")?)
+            }
+            IFLA_BRPORT_PROTECT => {
+                Protect(parse_u8(payload).context("invalid IFLA_BRPORT_PROTECT value")?)
+            }
+            IFLA_BRPORT_FAST_LEAVE => {
+                FastLeave(parse_u8(payload).context("invalid IFLA_BRPORT_FAST_LEAVE value")?)
+            }
+            IFLA_BRPORT_LEARNING => {
+                Learning(parse_u8(payload).context("invalid IFLA_BRPORT_LEARNING value")?)
+            }
+            IFLA_BRPORT_UNICAST_FLOOD => {
+                UnicastFlood(parse_u8(payload).context("invalid IFLA_BRPORT_UNICAST_FLOOD value")?)
+            }
+            IFLA_BRPORT_PROXYARP => {
+                ProxyArp(parse_u8(payload).context("invalid IFLA_BRPORT_PROXYARP value")?)
+            }
+            IFLA_BRPORT_LEARNING_SYNC => {
+                LearningSync(parse_u8(payload).context("invalid IFLA_BRPORT_LEARNING_SYNC value")?)
+            }
+            IFLA_BRPORT_PROXYARP_WIFI => {
+                ProxyArpWifi(parse_u8(payload).context("invalid IFLA_BRPORT_PROXYARP_WIFI value")?)
+            }
+            IFLA_BRPORT_DESIGNATED_PORT => DesignatedPort(
+                parse_u16(payload).context("invalid IFLA_BRPORT_DESIGNATED_PORT value")?,
+            ),
+            IFLA_BRPORT_DESIGNATED_COST => DesignatedCost(
+                parse_u16(payload).context("invalid IFLA_BRPORT_DESIGNATED_COST value")?,
+            ),
+            IFLA_BRPORT_ID => Id(parse_u16(payload).context("invalid IFLA_BRPORT_ID value")?),
+            IFLA_BRPORT_NO => No(parse_u16(payload).context("invalid IFLA_BRPORT_NO value")?),
+            IFLA_BRPORT_TOPOLOGY_CHANGE_ACK => TopologyChangeAck(
+                parse_u8(payload).context("invalid IFLA_BRPORT_TOPOLOGY_CHANGE_ACK value")?,
+            ),
+            IFLA_BRPORT_CONFIG_PENDING => ConfigPending(
+                parse_u8(payload).context("invalid IFLA_BRPORT_CONFIG_PENDING value")?,
+            ),
+            IFLA_BRPORT_MESSAGE_AGE_TIMER => MessageAgeTimer(
+                parse_u64(payload).context("invalid IFLA_BRPORT_MESSAGE_AGE_TIMER value")?,
+            ),
+            IFLA_BRPORT_FORWARD_DELAY_TIMER => ForwardDelayTimer(
+                parse_u64(payload).context("invalid IFLA_BRPORT_FORWARD_DELAY_TIMER value")?,
+            ),
+            IFLA_BRPORT_HOLD_TIMER => {
+                HoldTimer(parse_u64(payload).context("invalid IFLA_BRPORT_HOLD_TIMER value")?)
+            }
+            IFLA_BRPORT_FLUSH => Flush,
+            IFLA_BRPORT_MULTICAST_ROUTER => MulticastRouter(
+                parse_u8(payload).context("invalid IFLA_BRPORT_MULTICAST_ROUTER value")?,
+            ),
+            IFLA_BRPORT_PAD => Pad(payload.to_vec()),
+            IFLA_BRPORT_MCAST_FLOOD => {
+                MulticastFlood(parse_u8(payload).context("invalid IFLA_BRPORT_MCAST_FLOOD value")?)
+            }
+            IFLA_BRPORT_MCAST_TO_UCAST => MulticastToUnicast(
+                parse_u8(payload).context("invalid IFLA_BRPORT_MCAST_TO_UCAST value")?,
+            ),
+            IFLA_BRPORT_VLAN_TUNNEL => {
+                VlanTunnel(parse_u8(payload).context("invalid IFLA_BRPORT_VLAN_TUNNEL value")?)
+            }
+            IFLA_BRPORT_BCAST_FLOOD => {
+                BroadcastFlood(parse_u8(payload).context("invalid IFLA_BRPORT_BCAST_FLOOD value")?)
+            }
+            IFLA_BRPORT_GROUP_FWD_MASK => GroupFwdMask(
+                parse_u16(payload).context("invalid IFLA_BRPORT_GROUP_FWD_MASK value")?,
+            ),
+            IFLA_BRPORT_NEIGH_SUPPRESS => NeighSuppress(
+                parse_u8(payload).context("invalid IFLA_BRPORT_NEIGH_SUPPRESS value")?,
+            ),
+            IFLA_BRPORT_ISOLATED => {
+                Isolated(parse_u8(payload).context("invalid IFLA_BRPORT_ISOLATED value")?)
+            }
+            IFLA_BRPORT_BACKUP_PORT => {
+                BackupPort(parse_u32(payload).context("invalid IFLA_BRPORT_BACKUP_PORT value")?)
+            }
+            IFLA_BRPORT_ROOT_ID | IFLA_BRPORT_BRIDGE_ID => {
+                if payload.len() != 8 {
+                    return Err("invalid IFLA_BRPORT_ROOT_ID or IFLA_BRPORT_BRIDGE_ID value".into());
+                }
+
+                let priority = NativeEndian::read_u16(&payload[..2]);
+                let address = parse_mac(&payload[2..])
+                    .context("invalid IFLA_BRPORT_ROOT_ID or IFLA_BRPORT_BRIDGE_ID value")?;
+
+                match buf.kind() {
+                    IFLA_BRPORT_ROOT_ID => RootId((priority, address)),
+                    IFLA_BRPORT_BRIDGE_ID => BridgeId((priority, address)),
+                    _ => unreachable!(),
+                }
+            }
+            _ => Other(
+                DefaultNla::parse(buf)
+                    .context("invalid link info bridge port NLA value (unknown type)")?,
+            ),
+        })
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum VethInfo {
     Unspec(Vec<u8>),
@@ -1238,6 +2249,218 @@ mod tests {
         assert_eq!(expected, parsed);
     }
 
+    // IFLA_LINKINFO of `ip link add link eth0 macsec0 type macsec port 1 encrypt off`, where eth0
+    // has the 52:54:00:d7:19:3e MAC address, with the attributes in the order macsec_fill_info()
+    // puts them.
+    #[rustfmt::skip]
+    static MACSEC: [u8; 120] = [
+        0x0b, 0x00, // L = 11
+        0x01, 0x00, // T = 1 (IFLA_INFO_KIND)
+        0x6d, 0x61, 0x63, 0x73, 0x65, 0x63, 0x00, // V = "macsec"
+        0x00, // padding
+
+        0x6c, 0x00, // L = 108
+        0x02, 0x00, // T = 2 (IFLA_INFO_DATA)
+
+            0x0c, 0x00, // L = 12
+            0x01, 0x00, // T = 1 (IFLA_MACSEC_SCI)
+            0x52, 0x54, 0x00, 0xd7, 0x19, 0x3e, // V = MAC address...
+            0x00, 0x01, // ... and port 1, in network byte order
+
+            0x05, 0x00, // L = 5
+            0x03, 0x00, // T = 3 (IFLA_MACSEC_ICV_LEN)
+            0x10, // V = 16
+            0x00, 0x00, 0x00, // padding
+
+            0x0c, 0x00, // L = 12
+            0x04, 0x00, // T = 4 (IFLA_MACSEC_CIPHER_SUITE)
+            0x01, 0x00, 0x00, 0x01, 0x00, 0x02, 0x80, 0x00, // V = MACSEC_DEFAULT_CIPHER_ID
+
+            0x05, 0x00, // L = 5
+            0x06, 0x00, // T = 6 (IFLA_MACSEC_ENCODING_SA)
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // padding
+
+            0x05, 0x00, // L = 5
+            0x07, 0x00, // T = 7 (IFLA_MACSEC_ENCRYPT)
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // padding
+
+            0x05, 0x00, // L = 5
+            0x08, 0x00, // T = 8 (IFLA_MACSEC_PROTECT)
+            0x01, // V = 1
+            0x00, 0x00, 0x00, // padding
+
+            0x05, 0x00, // L = 5
+            0x09, 0x00, // T = 9 (IFLA_MACSEC_INC_SCI)
+            0x01, // V = 1
+            0x00, 0x00, 0x00, // padding
+
+            0x05, 0x00, // L = 5
+            0x0a, 0x00, // T = 10 (IFLA_MACSEC_ES)
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // padding
+
+            0x05, 0x00, // L = 5
+            0x0b, 0x00, // T = 11 (IFLA_MACSEC_SCB)
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // padding
+
+            0x05, 0x00, // L = 5
+            0x0c, 0x00, // T = 12 (IFLA_MACSEC_REPLAY_PROTECT)
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // padding
+
+            0x05, 0x00, // L = 5
+            0x0d, 0x00, // T = 13 (IFLA_MACSEC_VALIDATION)
+            0x02, // V = 2 (MACSEC_VALIDATE_STRICT)
+            0x00, 0x00, 0x00, // padding
+
+            0x05, 0x00, // L = 5
+            0x0f, 0x00, // T = 15 (IFLA_MACSEC_OFFLOAD)
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // padding
+    ];
+
+    lazy_static! {
+        static ref MACSEC_INFO: Vec<InfoMacSec> = vec![
+            InfoMacSec::Sci(0x5254_00d7_193e_0001),
+            InfoMacSec::IcvLen(16),
+            InfoMacSec::CipherSuite(MACSEC_DEFAULT_CIPHER_ID),
+            InfoMacSec::EncodingSa(0),
+            InfoMacSec::Encrypt(0),
+            InfoMacSec::Protect(1),
+            InfoMacSec::IncSci(1),
+            InfoMacSec::Es(0),
+            InfoMacSec::Scb(0),
+            InfoMacSec::ReplayProtect(0),
+            InfoMacSec::Validation(MACSEC_VALIDATE_STRICT),
+            InfoMacSec::Offload(0),
+        ];
+    }
+
+    #[test]
+    fn parse_info_macsec() {
+        let nla = NlaBuffer::new_checked(&MACSEC[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::MacSec),
+            Info::Data(InfoData::MacSec(MACSEC_INFO.clone())),
+        ];
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn emit_info_macsec() {
+        let nlas = vec![
+            Info::Kind(InfoKind::MacSec),
+            Info::Data(InfoData::MacSec(MACSEC_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 120);
+
+        let mut vec = vec![0xff; 120];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &MACSEC[..]);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn parse_info_geneve() {
+        let data = vec![
+            0x0b, 0x00, // L = 11
+            0x01, 0x00, // T = 1 (IFLA_INFO_KIND)
+            0x67, 0x65, 0x6e, 0x65, 0x76, 0x65, 0x00, // V = "geneve"
+            0x00, // padding
+
+            0x24, 0x00, // L = 36
+            0x02, 0x00, // T = 2 (IFLA_INFO_DATA)
+
+                0x08, 0x00, // L = 8
+                0x01, 0x00, // T = 1 (IFLA_GENEVE_ID)
+                0x2a, 0x00, 0x00, 0x00, // V = 42
+
+                0x08, 0x00, // L = 8
+                0x02, 0x00, // T = 2 (IFLA_GENEVE_REMOTE)
+                0xc0, 0xa8, 0x00, 0x01, // V = 192.168.0.1
+
+                0x06, 0x00, // L = 6
+                0x05, 0x00, // T = 5 (IFLA_GENEVE_PORT)
+                0x17, 0xc1, // V = 6081 (network byte order)
+                0x00, 0x00, // padding
+
+                0x05, 0x00, // L = 5
+                0x08, 0x00, // T = 8 (IFLA_GENEVE_UDP_CSUM)
+                0x00, // V = 0
+                0x00, 0x00, 0x00, // padding
+        ];
+        let nla = NlaBuffer::new_checked(&data[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::Geneve),
+            Info::Data(InfoData::Geneve(vec![
+                InfoGeneve::Id(42),
+                InfoGeneve::Remote(Ipv4Addr::new(192, 168, 0, 1)),
+                InfoGeneve::Port(6081),
+                InfoGeneve::UdpCsum(0),
+            ])),
+        ];
+        assert_eq!(expected, parsed);
+
+        let mut vec = vec![0xff; 48];
+        expected.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &data[..]);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn parse_info_bridge_port() {
+        let data = vec![
+            0x0b, 0x00, // L = 11
+            0x04, 0x00, // T = 4 (IFLA_INFO_SLAVE_KIND)
+            0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x00, // V = "bridge"
+            0x00, // padding
+
+            0x24, 0x00, // L = 36
+            0x05, 0x00, // T = 5 (IFLA_INFO_SLAVE_DATA)
+
+                0x05, 0x00, // L = 5
+                0x01, 0x00, // T = 1 (IFLA_BRPORT_STATE)
+                0x03, // V = 3 (BR_STATE_FORWARDING)
+                0x00, 0x00, 0x00, // padding
+
+                0x06, 0x00, // L = 6
+                0x02, 0x00, // T = 2 (IFLA_BRPORT_PRIORITY)
+                0x20, 0x00, // V = 32
+                0x00, 0x00, // padding
+
+                0x08, 0x00, // L = 8
+                0x03, 0x00, // T = 3 (IFLA_BRPORT_COST)
+                0x64, 0x00, 0x00, 0x00, // V = 100
+
+                0x05, 0x00, // L = 5
+                0x08, 0x00, // T = 8 (IFLA_BRPORT_LEARNING)
+                0x01, // V = 1
+                0x00, 0x00, 0x00, // padding
+        ];
+        let nla = NlaBuffer::new_checked(&data[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::SlaveKind(InfoSlaveKind::Bridge),
+            Info::SlaveData(InfoSlaveData::Bridge(vec![
+                InfoBridgePort::State(BR_STATE_FORWARDING),
+                InfoBridgePort::Priority(32),
+                InfoBridgePort::Cost(100),
+                InfoBridgePort::Learning(1),
+            ])),
+        ];
+        assert_eq!(expected, parsed);
+
+        let mut vec = vec![0xff; 48];
+        expected.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &data[..]);
+    }
+
     #[test]
     fn parse() {
         let nla = NlaBuffer::new_checked(&BRIDGE[..]).unwrap();
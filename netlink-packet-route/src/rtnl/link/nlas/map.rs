@@ -3,6 +3,12 @@ use crate::{
     DecodeError,
 };
 
+// `struct rtnl_link_ifmap` is padded to the alignment of its `u64` fields, so it is 32 bytes long,
+// except on 32 bits x86 where `u64` is only 4 bytes aligned. The kernel rejects IFLA_MAP
+// attributes shorter than that.
+#[cfg(not(target_arch = "x86"))]
+pub const LINK_MAP_LEN: usize = 32;
+#[cfg(target_arch = "x86")]
 pub const LINK_MAP_LEN: usize = 28;
 buffer!(MapBuffer(LINK_MAP_LEN) {
     memory_start: (u64, 0..8),
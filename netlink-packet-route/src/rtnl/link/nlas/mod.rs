@@ -7,6 +7,9 @@ pub use self::inet6::*;
 mod af_spec_inet;
 pub use self::af_spec_inet::*;
 
+mod af_spec_bridge;
+pub use self::af_spec_bridge::*;
+
 mod link_infos;
 pub use self::link_infos::*;
 
@@ -22,10 +25,22 @@ pub use self::stats64::*;
 mod link_state;
 pub use self::link_state::*;
 
+mod link_event;
+pub use self::link_event::*;
+
+mod xdp;
+pub use self::xdp::*;
+
+mod vf;
+pub use self::vf::*;
+
+mod prop_list;
+pub use self::prop_list::*;
+
 #[cfg(test)]
 mod tests;
 
-use std::os::unix::io::RawFd;
+use std::{borrow::Cow, os::unix::io::RawFd};
 
 use byteorder::{ByteOrder, NativeEndian};
 use failure::ResultExt;
@@ -45,22 +60,16 @@ pub enum Nla {
     Cost(Vec<u8>),
     Priority(Vec<u8>),
     Weight(Vec<u8>),
-    VfInfoList(Vec<u8>),
     VfPorts(Vec<u8>),
     PortSelf(Vec<u8>),
     PhysPortId(Vec<u8>),
     PhysSwitchId(Vec<u8>),
     Pad(Vec<u8>),
-    Xdp(Vec<u8>),
-    Event(Vec<u8>),
-    NewNetnsId(Vec<u8>),
-    IfNetnsId(Vec<u8>),
-    CarrierUpCount(Vec<u8>),
-    CarrierDownCount(Vec<u8>),
-    NewIfIndex(Vec<u8>),
     Info(Vec<Info>),
+    Xdp(Vec<Xdp>),
+    VfInfoList(Vec<VfInfo>),
+    PropList(Vec<Prop>),
     Wireless(Vec<u8>),
-    ProtoInfo(Vec<u8>),
     // mac address (use to be [u8; 6] but it turns out MAC != HW address, for instance for IP over
     // GRE where it's an IPv4!)
     Address(Vec<u8>),
@@ -71,6 +80,7 @@ pub enum Nla {
     // seems that for certain attriutes, this matter:
     // https://elixir.bootlin.com/linux/v4.17-rc5/source/net/core/rtnetlink.c#L1660
     IfName(String),
+    AltIfName(String),
     Qdisc(String),
     IfAlias(String),
     PhysPortName(String),
@@ -98,14 +108,24 @@ pub enum Nla {
     NetnsId(i32),
     // custom
     OperState(State),
-    Stats(Vec<u8>),
-    Stats64(Vec<u8>),
-    Map(Vec<u8>),
+    Event(LinkEvent),
+    NewNetnsId(i32),
+    IfNetnsId(i32),
+    CarrierUpCount(u32),
+    CarrierDownCount(u32),
+    NewIfIndex(i32),
+    Stats(Stats),
+    Stats64(Stats64),
+    Map(Map),
     // AF_SPEC (the type of af_spec depends on the interface family of the message)
     AfSpecInet(Vec<AfSpecInet>),
-    // AfSpecBridge(Vec<AfSpecBridgeNla>),
-    AfSpecBridge(Vec<u8>),
+    AfSpecBridge(Vec<AfSpecBridge>),
     AfSpecUnknown(Vec<u8>),
+    /// Per-link IPv6 information, sent in `AF_INET6` link messages
+    ProtoInfoInet6(Vec<Inet6>),
+    /// Bridge port information, sent in `AF_BRIDGE` link messages
+    ProtoInfoBridge(Vec<InfoBridgePort>),
+    ProtoInfoUnknown(Vec<u8>),
     Other(DefaultNla),
 }
 
@@ -119,30 +139,21 @@ impl nlas::Nla for Nla {
                 | Cost(ref bytes)
                 | Priority(ref bytes)
                 | Weight(ref bytes)
-                | VfInfoList(ref bytes)
                 | VfPorts(ref bytes)
                 | PortSelf(ref bytes)
                 | PhysPortId(ref bytes)
                 | PhysSwitchId(ref bytes)
                 | Pad(ref bytes)
-                | Xdp(ref bytes)
-                | Event(ref bytes)
-                | NewNetnsId(ref bytes)
-                | IfNetnsId(ref bytes)
                 | Wireless(ref bytes)
-                | ProtoInfo(ref bytes)
-                | CarrierUpCount(ref bytes)
-                | CarrierDownCount(ref bytes)
-                | NewIfIndex(ref bytes)
+                | ProtoInfoUnknown(ref bytes)
                 | Address(ref bytes)
                 | Broadcast(ref bytes)
                 | AfSpecUnknown(ref bytes)
-                | AfSpecBridge(ref bytes)
-                | Map(ref bytes)
                 => bytes.len(),
 
             // strings: +1 because we need to append a nul byte
             IfName(ref string)
+                | AltIfName(ref string)
                 | Qdisc(ref string)
                 | IfAlias(ref string)
                 | PhysPortName(ref string)
@@ -170,15 +181,27 @@ impl nlas::Nla for Nla {
                 | CarrierChanges(_)
                 | GsoMaxSegs(_)
                 | GsoMaxSize(_)
-                | NetnsId(_) => 4,
+                | NetnsId(_)
+                | Event(_)
+                | NewNetnsId(_)
+                | IfNetnsId(_)
+                | CarrierUpCount(_)
+                | CarrierDownCount(_)
+                | NewIfIndex(_) => 4,
 
             // Defaults
             OperState(_) => 1,
-            Stats(_) => LINK_STATS_LEN,
-            Stats64(_) => LINK_STATS64_LEN,
+            Stats(ref stats) => stats.buffer_len(),
+            Stats64(ref stats) => stats.buffer_len(),
+            Map(ref map) => map.buffer_len(),
             Info(ref nlas) => nlas.as_slice().buffer_len(),
+            Xdp(ref nlas) => nlas.as_slice().buffer_len(),
+            VfInfoList(ref nlas) => nlas.as_slice().buffer_len(),
+            PropList(ref nlas) => nlas.as_slice().buffer_len(),
             AfSpecInet(ref nlas) => nlas.as_slice().buffer_len(),
-            // AfSpecBridge(ref nlas) => nlas.as_slice().buffer_len(),
+            AfSpecBridge(ref nlas) => nlas.as_slice().buffer_len(),
+            ProtoInfoInet6(ref nlas) => nlas.as_slice().buffer_len(),
+            ProtoInfoBridge(ref nlas) => nlas.as_slice().buffer_len(),
             Other(ref attr)  => attr.value_len(),
         }
     }
@@ -192,34 +215,23 @@ impl nlas::Nla for Nla {
                 | Cost(ref bytes)
                 | Priority(ref bytes)
                 | Weight(ref bytes)
-                | VfInfoList(ref bytes)
                 | VfPorts(ref bytes)
                 | PortSelf(ref bytes)
                 | PhysPortId(ref bytes)
                 | PhysSwitchId(ref bytes)
                 | Wireless(ref bytes)
-                | ProtoInfo(ref bytes)
+                | ProtoInfoUnknown(ref bytes)
                 | Pad(ref bytes)
-                | Xdp(ref bytes)
-                | Event(ref bytes)
-                | NewNetnsId(ref bytes)
-                | IfNetnsId(ref bytes)
-                | CarrierUpCount(ref bytes)
-                | CarrierDownCount(ref bytes)
-                | NewIfIndex(ref bytes)
                 // mac address (could be [u8; 6] or [u8; 4] for example. Not sure if we should have
                 // a separate type for them
                 | Address(ref bytes)
                 | Broadcast(ref bytes)
                 | AfSpecUnknown(ref bytes)
-                | AfSpecBridge(ref bytes)
-                | Stats(ref bytes)
-                | Stats64(ref bytes)
-                | Map(ref bytes)
                 => buffer.copy_from_slice(bytes.as_slice()),
 
             // String
             IfName(ref string)
+                | AltIfName(ref string)
                 | Qdisc(ref string)
                 | IfAlias(ref string)
                 | PhysPortName(ref string)
@@ -249,16 +261,31 @@ impl nlas::Nla for Nla {
                 | CarrierChanges(ref value)
                 | GsoMaxSegs(ref value)
                 | GsoMaxSize(ref value)
+                | CarrierUpCount(ref value)
+                | CarrierDownCount(ref value)
                 => NativeEndian::write_u32(buffer, *value),
 
             NetnsId(ref value)
                 | NetNsFd(ref value)
+                | NewNetnsId(ref value)
+                | IfNetnsId(ref value)
+                | NewIfIndex(ref value)
                 => NativeEndian::write_i32(buffer, *value),
 
+            Event(event) => NativeEndian::write_u32(buffer, event.into()),
+
             OperState(state) => buffer[0] = state.into(),
+            Stats(ref stats) => stats.emit(buffer),
+            Stats64(ref stats) => stats.emit(buffer),
+            Map(ref map) => map.emit(buffer),
             Info(ref nlas) => nlas.as_slice().emit(buffer),
+            Xdp(ref nlas) => nlas.as_slice().emit(buffer),
+            VfInfoList(ref nlas) => nlas.as_slice().emit(buffer),
+            PropList(ref nlas) => nlas.as_slice().emit(buffer),
             AfSpecInet(ref nlas) => nlas.as_slice().emit(buffer),
-            // AfSpecBridge(ref nlas) => nlas.as_slice().emit(buffer),
+            AfSpecBridge(ref nlas) => nlas.as_slice().emit(buffer),
+            ProtoInfoInet6(ref nlas) => nlas.as_slice().emit(buffer),
+            ProtoInfoBridge(ref nlas) => nlas.as_slice().emit(buffer),
             // default nlas
             Other(ref attr) => attr.emit_value(buffer),
         }
@@ -273,13 +300,14 @@ impl nlas::Nla for Nla {
             Priority(_) => IFLA_PRIORITY,
             Weight(_) => IFLA_WEIGHT,
             VfInfoList(_) => IFLA_VFINFO_LIST,
+            PropList(_) => IFLA_PROP_LIST,
             VfPorts(_) => IFLA_VF_PORTS,
             PortSelf(_) => IFLA_PORT_SELF,
             PhysPortId(_) => IFLA_PHYS_PORT_ID,
             PhysSwitchId(_) => IFLA_PHYS_SWITCH_ID,
             Info(_) => IFLA_LINKINFO,
             Wireless(_) => IFLA_WIRELESS,
-            ProtoInfo(_) => IFLA_PROTINFO,
+            ProtoInfoInet6(_) | ProtoInfoBridge(_) | ProtoInfoUnknown(_) => IFLA_PROTINFO,
             Pad(_) => IFLA_PAD,
             Xdp(_) => IFLA_XDP,
             Event(_) => IFLA_EVENT,
@@ -293,6 +321,7 @@ impl nlas::Nla for Nla {
             Broadcast(_) => IFLA_BROADCAST,
             // String
             IfName(_) => IFLA_IFNAME,
+            AltIfName(_) => IFLA_ALT_IFNAME,
             Qdisc(_) => IFLA_QDISC,
             IfAlias(_) => IFLA_IFALIAS,
             PhysPortName(_) => IFLA_PHYS_PORT_NAME,
@@ -327,6 +356,11 @@ impl nlas::Nla for Nla {
             Other(ref attr) => attr.kind(),
         }
     }
+
+    fn is_nested(&self) -> bool {
+        // The bridge tells nested port attributes from the legacy port state with this flag
+        matches!(*self, Nla::ProtoInfoBridge(_))
+    }
 }
 
 impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, u16> for Nla {
@@ -342,27 +376,41 @@ impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, u16> f
             IFLA_COST => Cost(payload.to_vec()),
             IFLA_PRIORITY => Priority(payload.to_vec()),
             IFLA_WEIGHT => Weight(payload.to_vec()),
-            IFLA_VFINFO_LIST => VfInfoList(payload.to_vec()),
             IFLA_VF_PORTS => VfPorts(payload.to_vec()),
             IFLA_PORT_SELF => PortSelf(payload.to_vec()),
             IFLA_PHYS_PORT_ID => PhysPortId(payload.to_vec()),
             IFLA_PHYS_SWITCH_ID => PhysSwitchId(payload.to_vec()),
             IFLA_WIRELESS => Wireless(payload.to_vec()),
-            IFLA_PROTINFO => ProtoInfo(payload.to_vec()),
             IFLA_PAD => Pad(payload.to_vec()),
-            IFLA_XDP => Xdp(payload.to_vec()),
-            IFLA_EVENT => Event(payload.to_vec()),
-            IFLA_NEW_NETNSID => NewNetnsId(payload.to_vec()),
-            IFLA_IF_NETNSID => IfNetnsId(payload.to_vec()),
-            IFLA_CARRIER_UP_COUNT => CarrierUpCount(payload.to_vec()),
-            IFLA_CARRIER_DOWN_COUNT => CarrierDownCount(payload.to_vec()),
-            IFLA_NEW_IFINDEX => NewIfIndex(payload.to_vec()),
+            IFLA_EVENT => Event(
+                parse_u32(payload)
+                    .context("invalid IFLA_EVENT value")?
+                    .into(),
+            ),
+            IFLA_NEW_NETNSID => {
+                NewNetnsId(parse_i32(payload).context("invalid IFLA_NEW_NETNSID value")?)
+            }
+            IFLA_IF_NETNSID => {
+                IfNetnsId(parse_i32(payload).context("invalid IFLA_IF_NETNSID value")?)
+            }
+            IFLA_CARRIER_UP_COUNT => {
+                CarrierUpCount(parse_u32(payload).context("invalid IFLA_CARRIER_UP_COUNT value")?)
+            }
+            IFLA_CARRIER_DOWN_COUNT => CarrierDownCount(
+                parse_u32(payload).context("invalid IFLA_CARRIER_DOWN_COUNT value")?,
+            ),
+            IFLA_NEW_IFINDEX => {
+                NewIfIndex(parse_i32(payload).context("invalid IFLA_NEW_IFINDEX value")?)
+            }
             // HW address (we parse them as Vec for now, because for IP over GRE, the HW address is
             // an IP instead of a MAC for example
             IFLA_ADDRESS => Address(payload.to_vec()),
             IFLA_BROADCAST => Broadcast(payload.to_vec()),
             // String
             IFLA_IFNAME => IfName(parse_string(payload).context("invalid IFLA_IFNAME value")?),
+            IFLA_ALT_IFNAME => {
+                AltIfName(parse_string(payload).context("invalid IFLA_ALT_IFNAME value")?)
+            }
             IFLA_QDISC => Qdisc(parse_string(payload).context("invalid IFLA_QDISC value")?),
             IFLA_IFALIAS => IfAlias(parse_string(payload).context("invalid IFLA_IFALIAS value")?),
             IFLA_PHYS_PORT_NAME => {
@@ -413,9 +461,24 @@ impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, u16> f
                     .context("invalid IFLA_OPERSTATE value")?
                     .into(),
             ),
-            IFLA_MAP => Map(payload.to_vec()),
-            IFLA_STATS => Stats(payload.to_vec()),
-            IFLA_STATS64 => Stats64(payload.to_vec()),
+            IFLA_MAP => {
+                let err = "invalid IFLA_MAP value";
+                let payload = zero_padded(payload, LINK_MAP_LEN);
+                let buf = MapBuffer::new_checked(&payload[..]).context(err)?;
+                Map(map::Map::parse(&buf).context(err)?)
+            }
+            IFLA_STATS => {
+                let err = "invalid IFLA_STATS value";
+                let payload = zero_padded(payload, LINK_STATS_LEN);
+                let buf = StatsBuffer::new_checked(&payload[..]).context(err)?;
+                Stats(stats::Stats::parse(&buf).context(err)?)
+            }
+            IFLA_STATS64 => {
+                let err = "invalid IFLA_STATS64 value";
+                let payload = zero_padded(payload, LINK_STATS64_LEN);
+                let buf = Stats64Buffer::new_checked(&payload[..]).context(err)?;
+                Stats64(stats64::Stats64::parse(&buf).context(err)?)
+            }
             IFLA_AF_SPEC => match interface_family as u16 {
                 AF_INET | AF_INET6 | AF_UNSPEC => {
                     let mut nlas = vec![];
@@ -426,9 +489,65 @@ impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, u16> f
                     }
                     AfSpecInet(nlas)
                 }
-                AF_BRIDGE => AfSpecBridge(payload.to_vec()),
+                AF_BRIDGE => {
+                    let mut nlas = vec![];
+                    let err = "invalid IFLA_AF_SPEC value";
+                    for nla in NlasIterator::new(payload) {
+                        let nla = nla.context(err)?;
+                        nlas.push(af_spec_bridge::AfSpecBridge::parse(&nla).context(err)?);
+                    }
+                    AfSpecBridge(nlas)
+                }
                 _ => AfSpecUnknown(payload.to_vec()),
             },
+            IFLA_PROTINFO => match interface_family {
+                AF_INET6 => {
+                    let mut nlas = vec![];
+                    let err = "invalid IFLA_PROTINFO value";
+                    for nla in NlasIterator::new(payload) {
+                        let nla = nla.context(err)?;
+                        nlas.push(inet6::Inet6::parse(&nla).context(err)?);
+                    }
+                    ProtoInfoInet6(nlas)
+                }
+                AF_BRIDGE if buf.nested_flag() => {
+                    let mut nlas = vec![];
+                    let err = "invalid IFLA_PROTINFO value";
+                    for nla in NlasIterator::new(payload) {
+                        let nla = nla.context(err)?;
+                        nlas.push(link_infos::InfoBridgePort::parse(&nla).context(err)?);
+                    }
+                    ProtoInfoBridge(nlas)
+                }
+                _ => ProtoInfoUnknown(payload.to_vec()),
+            },
+            IFLA_XDP => {
+                let err = "invalid IFLA_XDP value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(err)?;
+                    nlas.push(xdp::Xdp::parse(&nla).context(err)?);
+                }
+                Xdp(nlas)
+            }
+            IFLA_VFINFO_LIST => {
+                let err = "invalid IFLA_VFINFO_LIST value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(err)?;
+                    nlas.push(vf::VfInfo::parse(&nla).context(err)?);
+                }
+                VfInfoList(nlas)
+            }
+            IFLA_PROP_LIST => {
+                let err = "invalid IFLA_PROP_LIST value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(err)?;
+                    nlas.push(Prop::parse(&nla).context(err)?);
+                }
+                PropList(nlas)
+            }
             IFLA_LINKINFO => {
                 let err = "invalid IFLA_LINKINFO value";
                 let buf = NlaBuffer::new_checked(payload).context(err)?;
@@ -439,3 +558,17 @@ impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, u16> f
         })
     }
 }
+
+/// Pad `payload` with zeros up to `len` bytes. The kernel structures sent in IFLA_STATS and
+/// IFLA_STATS64 grew over time (`rx_nohandler` only exists since Linux 4.6), so older kernels send
+/// shorter attributes, whose missing trailing fields are read as zeros. Similarly, IFLA_MAP is
+/// shorter when it comes from a 32 bits x86 kernel.
+fn zero_padded(payload: &[u8], len: usize) -> Cow<'_, [u8]> {
+    if payload.len() >= len {
+        Cow::Borrowed(payload)
+    } else {
+        let mut padded = payload.to_vec();
+        padded.resize(len, 0);
+        Cow::Owned(padded)
+    }
+}
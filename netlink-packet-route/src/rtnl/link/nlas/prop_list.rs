@@ -0,0 +1,59 @@
+use failure::ResultExt;
+
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_string,
+    traits::Parseable,
+    DecodeError,
+};
+
+/// An entry of the `IFLA_PROP_LIST` attribute
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Prop {
+    /// Alternative name of the link, which unlike `IFLA_IFNAME` can be longer than `IFNAMSIZ`
+    AltIfName(String),
+    Other(DefaultNla),
+}
+
+impl Nla for Prop {
+    fn value_len(&self) -> usize {
+        use self::Prop::*;
+        match *self {
+            AltIfName(ref string) => string.len() + 1,
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Prop::*;
+        match *self {
+            AltIfName(ref string) => {
+                buffer[..string.len()].copy_from_slice(string.as_bytes());
+                buffer[string.len()] = 0;
+            }
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Prop::*;
+        match *self {
+            AltIfName(_) => IFLA_ALT_IFNAME,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Prop {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Prop::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_ALT_IFNAME => {
+                AltIfName(parse_string(payload).context("invalid IFLA_ALT_IFNAME value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -1,9 +1,12 @@
+use std::time::Duration;
+
+use super::{Stats64, StatsRates};
 use crate::{
     traits::{Emitable, Parseable},
     DecodeError,
 };
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub struct Stats {
     /// total packets received
     pub rx_packets: u32,
@@ -53,6 +56,58 @@ pub struct Stats {
     pub rx_nohandler: u32,
 }
 
+impl Stats {
+    /// Return the difference between these counters and the counters of an earlier sample
+    /// (`previous`) of the same link. The kernel counters are allowed to wrap around between the
+    /// two samples.
+    pub fn delta(&self, previous: &Stats) -> Stats {
+        Stats {
+            rx_packets: self.rx_packets.wrapping_sub(previous.rx_packets),
+            tx_packets: self.tx_packets.wrapping_sub(previous.tx_packets),
+            rx_bytes: self.rx_bytes.wrapping_sub(previous.rx_bytes),
+            tx_bytes: self.tx_bytes.wrapping_sub(previous.tx_bytes),
+            rx_errors: self.rx_errors.wrapping_sub(previous.rx_errors),
+            tx_errors: self.tx_errors.wrapping_sub(previous.tx_errors),
+            rx_dropped: self.rx_dropped.wrapping_sub(previous.rx_dropped),
+            tx_dropped: self.tx_dropped.wrapping_sub(previous.tx_dropped),
+            multicast: self.multicast.wrapping_sub(previous.multicast),
+            collisions: self.collisions.wrapping_sub(previous.collisions),
+            rx_length_errors: self
+                .rx_length_errors
+                .wrapping_sub(previous.rx_length_errors),
+            rx_over_errors: self.rx_over_errors.wrapping_sub(previous.rx_over_errors),
+            rx_crc_errors: self.rx_crc_errors.wrapping_sub(previous.rx_crc_errors),
+            rx_frame_errors: self.rx_frame_errors.wrapping_sub(previous.rx_frame_errors),
+            rx_fifo_errors: self.rx_fifo_errors.wrapping_sub(previous.rx_fifo_errors),
+            rx_missed_errors: self
+                .rx_missed_errors
+                .wrapping_sub(previous.rx_missed_errors),
+            tx_aborted_errors: self
+                .tx_aborted_errors
+                .wrapping_sub(previous.tx_aborted_errors),
+            tx_carrier_errors: self
+                .tx_carrier_errors
+                .wrapping_sub(previous.tx_carrier_errors),
+            tx_fifo_errors: self.tx_fifo_errors.wrapping_sub(previous.tx_fifo_errors),
+            tx_heartbeat_errors: self
+                .tx_heartbeat_errors
+                .wrapping_sub(previous.tx_heartbeat_errors),
+            tx_window_errors: self
+                .tx_window_errors
+                .wrapping_sub(previous.tx_window_errors),
+            rx_compressed: self.rx_compressed.wrapping_sub(previous.rx_compressed),
+            tx_compressed: self.tx_compressed.wrapping_sub(previous.tx_compressed),
+            rx_nohandler: self.rx_nohandler.wrapping_sub(previous.rx_nohandler),
+        }
+    }
+
+    /// Return the per-second rates of the main counters between an earlier sample (`previous`)
+    /// of the same link and this one, `interval` being the time elapsed between the two samples.
+    pub fn rates(&self, previous: &Stats, interval: Duration) -> StatsRates {
+        StatsRates::from_delta(&Stats64::from(self.delta(previous)), interval)
+    }
+}
+
 pub const LINK_STATS_LEN: usize = 96;
 
 buffer!(StatsBuffer(LINK_STATS_LEN) {
@@ -1,3 +1,6 @@
+use std::time::Duration;
+
+use super::Stats;
 use crate::{
     traits::{Emitable, Parseable},
     DecodeError,
@@ -31,7 +34,7 @@ buffer!(Stats64Buffer(LINK_STATS64_LEN) {
     rx_nohandler: (u64, 184..192),
 });
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub struct Stats64 {
     /// total packets received
     pub rx_packets: u64,
@@ -145,3 +148,131 @@ impl Emitable for Stats64 {
         buffer.set_rx_nohandler(self.rx_nohandler);
     }
 }
+
+impl Stats64 {
+    /// Return the difference between these counters and the counters of an earlier sample
+    /// (`previous`) of the same link. The kernel counters are allowed to wrap around between the
+    /// two samples.
+    pub fn delta(&self, previous: &Stats64) -> Stats64 {
+        Stats64 {
+            rx_packets: self.rx_packets.wrapping_sub(previous.rx_packets),
+            tx_packets: self.tx_packets.wrapping_sub(previous.tx_packets),
+            rx_bytes: self.rx_bytes.wrapping_sub(previous.rx_bytes),
+            tx_bytes: self.tx_bytes.wrapping_sub(previous.tx_bytes),
+            rx_errors: self.rx_errors.wrapping_sub(previous.rx_errors),
+            tx_errors: self.tx_errors.wrapping_sub(previous.tx_errors),
+            rx_dropped: self.rx_dropped.wrapping_sub(previous.rx_dropped),
+            tx_dropped: self.tx_dropped.wrapping_sub(previous.tx_dropped),
+            multicast: self.multicast.wrapping_sub(previous.multicast),
+            collisions: self.collisions.wrapping_sub(previous.collisions),
+            rx_length_errors: self
+                .rx_length_errors
+                .wrapping_sub(previous.rx_length_errors),
+            rx_over_errors: self.rx_over_errors.wrapping_sub(previous.rx_over_errors),
+            rx_crc_errors: self.rx_crc_errors.wrapping_sub(previous.rx_crc_errors),
+            rx_frame_errors: self.rx_frame_errors.wrapping_sub(previous.rx_frame_errors),
+            rx_fifo_errors: self.rx_fifo_errors.wrapping_sub(previous.rx_fifo_errors),
+            rx_missed_errors: self
+                .rx_missed_errors
+                .wrapping_sub(previous.rx_missed_errors),
+            tx_aborted_errors: self
+                .tx_aborted_errors
+                .wrapping_sub(previous.tx_aborted_errors),
+            tx_carrier_errors: self
+                .tx_carrier_errors
+                .wrapping_sub(previous.tx_carrier_errors),
+            tx_fifo_errors: self.tx_fifo_errors.wrapping_sub(previous.tx_fifo_errors),
+            tx_heartbeat_errors: self
+                .tx_heartbeat_errors
+                .wrapping_sub(previous.tx_heartbeat_errors),
+            tx_window_errors: self
+                .tx_window_errors
+                .wrapping_sub(previous.tx_window_errors),
+            rx_compressed: self.rx_compressed.wrapping_sub(previous.rx_compressed),
+            tx_compressed: self.tx_compressed.wrapping_sub(previous.tx_compressed),
+            rx_nohandler: self.rx_nohandler.wrapping_sub(previous.rx_nohandler),
+        }
+    }
+
+    /// Return the per-second rates of the main counters between an earlier sample (`previous`)
+    /// of the same link and this one, `interval` being the time elapsed between the two samples.
+    pub fn rates(&self, previous: &Stats64, interval: Duration) -> StatsRates {
+        StatsRates::from_delta(&self.delta(previous), interval)
+    }
+}
+
+impl From<Stats> for Stats64 {
+    fn from(stats: Stats) -> Self {
+        Stats64 {
+            rx_packets: u64::from(stats.rx_packets),
+            tx_packets: u64::from(stats.tx_packets),
+            rx_bytes: u64::from(stats.rx_bytes),
+            tx_bytes: u64::from(stats.tx_bytes),
+            rx_errors: u64::from(stats.rx_errors),
+            tx_errors: u64::from(stats.tx_errors),
+            rx_dropped: u64::from(stats.rx_dropped),
+            tx_dropped: u64::from(stats.tx_dropped),
+            multicast: u64::from(stats.multicast),
+            collisions: u64::from(stats.collisions),
+            rx_length_errors: u64::from(stats.rx_length_errors),
+            rx_over_errors: u64::from(stats.rx_over_errors),
+            rx_crc_errors: u64::from(stats.rx_crc_errors),
+            rx_frame_errors: u64::from(stats.rx_frame_errors),
+            rx_fifo_errors: u64::from(stats.rx_fifo_errors),
+            rx_missed_errors: u64::from(stats.rx_missed_errors),
+            tx_aborted_errors: u64::from(stats.tx_aborted_errors),
+            tx_carrier_errors: u64::from(stats.tx_carrier_errors),
+            tx_fifo_errors: u64::from(stats.tx_fifo_errors),
+            tx_heartbeat_errors: u64::from(stats.tx_heartbeat_errors),
+            tx_window_errors: u64::from(stats.tx_window_errors),
+            rx_compressed: u64::from(stats.rx_compressed),
+            tx_compressed: u64::from(stats.tx_compressed),
+            rx_nohandler: u64::from(stats.rx_nohandler),
+        }
+    }
+}
+
+/// Per-second rates of the main link counters, computed from two samples with
+/// [`Stats64::rates`](struct.Stats64.html#method.rates) or
+/// [`Stats::rates`](struct.Stats.html#method.rates).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct StatsRates {
+    /// packets received per second
+    pub rx_packets: f64,
+    /// packets transmitted per second
+    pub tx_packets: f64,
+    /// bytes received per second
+    pub rx_bytes: f64,
+    /// bytes transmitted per second
+    pub tx_bytes: f64,
+    /// bad packets received per second
+    pub rx_errors: f64,
+    /// packet transmit problems per second
+    pub tx_errors: f64,
+    /// received packets dropped per second
+    pub rx_dropped: f64,
+    /// transmitted packets dropped per second
+    pub tx_dropped: f64,
+    /// multicast packets received per second
+    pub multicast: f64,
+}
+
+impl StatsRates {
+    pub(crate) fn from_delta(delta: &Stats64, interval: Duration) -> Self {
+        if interval == Duration::from_secs(0) {
+            return StatsRates::default();
+        }
+        let secs = interval.as_secs_f64();
+        StatsRates {
+            rx_packets: delta.rx_packets as f64 / secs,
+            tx_packets: delta.tx_packets as f64 / secs,
+            rx_bytes: delta.rx_bytes as f64 / secs,
+            tx_bytes: delta.tx_bytes as f64 / secs,
+            rx_errors: delta.rx_errors as f64 / secs,
+            tx_errors: delta.tx_errors as f64 / secs,
+            rx_dropped: delta.rx_dropped as f64 / secs,
+            tx_dropped: delta.tx_dropped as f64 / secs,
+            multicast: delta.multicast as f64 / secs,
+        }
+    }
+}
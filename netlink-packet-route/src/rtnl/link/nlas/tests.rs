@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use crate::{utils::nla::Nla, DecodeError};
 
 use super::*;
@@ -409,3 +411,391 @@ fn parse_af_inet6() {
     // Normally this is the end of the nla iterator
     assert!(nlas.next().is_none());
 }
+
+#[rustfmt::skip]
+static AF_SPEC_BRIDGE: [u8; 20] = [
+    // AF_SPEC (L=20, T=26)
+    0x14, 0x00, 0x1a, 0x00,
+        // IFLA_BRIDGE_FLAGS (L=6, T=0)
+        0x06, 0x00, 0x00, 0x00,
+        0x02, 0x00, // BRIDGE_FLAGS_SELF
+        0x00, 0x00, // padding
+
+        // IFLA_BRIDGE_VLAN_INFO (L=8, T=2)
+        0x08, 0x00, 0x02, 0x00,
+        0x06, 0x00, // flags: BRIDGE_VLAN_INFO_PVID | BRIDGE_VLAN_INFO_UNTAGGED
+        0x0a, 0x00, // vid: 10
+];
+
+#[test]
+fn parse_af_bridge() {
+    let buf = NlaBuffer::new_checked(&AF_SPEC_BRIDGE[..]).unwrap();
+    let parsed = super::Nla::parse_with_param(&buf, AF_BRIDGE).unwrap();
+    let expected = super::Nla::AfSpecBridge(vec![
+        AfSpecBridge::Flags(BRIDGE_FLAGS_SELF),
+        AfSpecBridge::VlanInfo(BridgeVlanInfo {
+            flags: BRIDGE_VLAN_INFO_PVID | BRIDGE_VLAN_INFO_UNTAGGED,
+            vid: 10,
+        }),
+    ]);
+    assert_eq!(parsed, expected);
+    assert_eq!(&get_byte_buffer(&expected)[..], &AF_SPEC_BRIDGE[..]);
+}
+
+#[test]
+fn stats64_round_trip() {
+    let nla = super::Nla::Stats64(Stats64 {
+        rx_packets: 3,
+        tx_packets: 4,
+        rx_bytes: 1 << 40,
+        tx_bytes: 42,
+        rx_nohandler: 7,
+        ..Default::default()
+    });
+    let bytes = get_byte_buffer(&nla);
+    assert_eq!(bytes.len(), LINK_STATS64_LEN + 4);
+
+    let buf = NlaBuffer::new_checked(&bytes[..]).unwrap();
+    assert_eq!(buf.kind(), IFLA_STATS64);
+    let parsed = super::Nla::parse_with_param(&buf, AF_UNSPEC).unwrap();
+    assert_eq!(parsed, nla);
+}
+
+// Build an attribute of the given type whose payload is `len` bytes long, each u32 (or u64 if
+// `wide`) field holding its index + 1.
+fn counters_nla(kind: u16, len: usize, wide: bool) -> Vec<u8> {
+    let mut bytes = vec![0; 4 + len];
+    NativeEndian::write_u16(&mut bytes[0..2], (4 + len) as u16);
+    NativeEndian::write_u16(&mut bytes[2..4], kind);
+    let size = if wide { 8 } else { 4 };
+    for (i, field) in bytes[4..].chunks_mut(size).enumerate() {
+        if wide {
+            NativeEndian::write_u64(field, i as u64 + 1);
+        } else {
+            NativeEndian::write_u32(field, i as u32 + 1);
+        }
+    }
+    bytes
+}
+
+#[test]
+fn parse_short_stats() {
+    // Kernels older than 4.6 do not send the rx_nohandler counter
+    let bytes = counters_nla(IFLA_STATS, LINK_STATS_LEN - 4, false);
+    let buf = NlaBuffer::new_checked(&bytes[..]).unwrap();
+    match super::Nla::parse_with_param(&buf, AF_UNSPEC).unwrap() {
+        super::Nla::Stats(stats) => {
+            assert_eq!(stats.rx_packets, 1);
+            assert_eq!(stats.tx_compressed, 23);
+            assert_eq!(stats.rx_nohandler, 0);
+        }
+        nla => panic!("unexpected nla {:?}", nla),
+    }
+
+    let bytes = counters_nla(IFLA_STATS64, LINK_STATS64_LEN - 8, true);
+    let buf = NlaBuffer::new_checked(&bytes[..]).unwrap();
+    match super::Nla::parse_with_param(&buf, AF_UNSPEC).unwrap() {
+        super::Nla::Stats64(stats) => {
+            assert_eq!(stats.rx_packets, 1);
+            assert_eq!(stats.tx_compressed, 23);
+            assert_eq!(stats.rx_nohandler, 0);
+        }
+        nla => panic!("unexpected nla {:?}", nla),
+    }
+}
+
+#[rustfmt::skip]
+static MAP_32_BITS: [u8; 32] = [
+    // IFLA_MAP (L=32, T=14), as sent by 32 bits x86 kernels
+    0x20, 0x00, 0x0e, 0x00,
+    0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // memory start
+    0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // memory end
+    0x00, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // base address
+    0x0b, 0x00, // irq
+    0x01, // dma
+    0x02, // port
+];
+
+#[test]
+fn parse_short_map() {
+    let buf = NlaBuffer::new_checked(&MAP_32_BITS[..]).unwrap();
+    let parsed = super::Nla::parse_with_param(&buf, AF_UNSPEC).unwrap();
+    let expected = super::Nla::Map(Map {
+        memory_start: 0x1000,
+        memory_end: 0x2000,
+        base_address: 0x3000,
+        irq: 11,
+        dma: 1,
+        port: 2,
+    });
+    assert_eq!(parsed, expected);
+}
+
+#[test]
+fn stats_delta_and_rates() {
+    let previous = Stats {
+        rx_packets: 10,
+        tx_packets: 5,
+        rx_bytes: u32::MAX - 9,
+        tx_bytes: 1000,
+        ..Default::default()
+    };
+    let current = Stats {
+        rx_packets: 30,
+        tx_packets: 5,
+        // the counter wrapped around
+        rx_bytes: 10,
+        tx_bytes: 3000,
+        ..Default::default()
+    };
+
+    let delta = current.delta(&previous);
+    assert_eq!(delta.rx_packets, 20);
+    assert_eq!(delta.tx_packets, 0);
+    assert_eq!(delta.rx_bytes, 20);
+    assert_eq!(delta.tx_bytes, 2000);
+
+    let rates = current.rates(&previous, Duration::from_secs(2));
+    assert_eq!(rates.rx_packets, 10.0);
+    assert_eq!(rates.tx_packets, 0.0);
+    assert_eq!(rates.rx_bytes, 10.0);
+    assert_eq!(rates.tx_bytes, 1000.0);
+
+    let rates = Stats64::from(current).rates(&Stats64::from(previous), Duration::from_secs(0));
+    assert_eq!(rates, StatsRates::default());
+}
+
+#[rustfmt::skip]
+static XDP: [u8; 28] = [
+    // IFLA_XDP (L=28, T=43)
+    0x1c, 0x00, 0x2b, 0x00,
+        // IFLA_XDP_ATTACHED (L=5, T=2)
+        0x05, 0x00, 0x02, 0x00,
+        0x02, // XDP_ATTACHED_SKB
+        0x00, 0x00, 0x00, // padding
+
+        // IFLA_XDP_SKB_PROG_ID (L=8, T=6)
+        0x08, 0x00, 0x06, 0x00,
+        0x2a, 0x00, 0x00, 0x00, // 42
+
+        // IFLA_XDP_PROG_ID (L=8, T=4)
+        0x08, 0x00, 0x04, 0x00,
+        0x2a, 0x00, 0x00, 0x00, // 42
+];
+
+#[test]
+fn parse_xdp() {
+    let buf = NlaBuffer::new_checked(&XDP[..]).unwrap();
+    let parsed = super::Nla::parse_with_param(&buf, AF_UNSPEC).unwrap();
+    let expected = super::Nla::Xdp(vec![
+        Xdp::Attached(XDP_ATTACHED_SKB),
+        Xdp::SkbProgId(42),
+        Xdp::ProgId(42),
+    ]);
+    assert_eq!(parsed, expected);
+    assert_eq!(&get_byte_buffer(&expected)[..], &XDP[..]);
+}
+
+#[rustfmt::skip]
+static VF_INFO_LIST: [u8; 92] = [
+    // IFLA_VFINFO_LIST (L=92, T=22)
+    0x5c, 0x00, 0x16, 0x00,
+        // IFLA_VF_INFO (L=88, T=1)
+        0x58, 0x00, 0x01, 0x00,
+            // IFLA_VF_MAC (L=40, T=1)
+            0x28, 0x00, 0x01, 0x00,
+            0x01, 0x00, 0x00, 0x00, // vf 1
+            0x52, 0x54, 0x00, 0x12, 0x34, 0x56, 0x00, 0x00, // mac, padded to 32 bytes
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+
+            // IFLA_VF_VLAN (L=16, T=2)
+            0x10, 0x00, 0x02, 0x00,
+            0x01, 0x00, 0x00, 0x00, // vf 1
+            0x64, 0x00, 0x00, 0x00, // vlan 100
+            0x03, 0x00, 0x00, 0x00, // qos 3
+
+            // IFLA_VF_TRUST (L=12, T=9)
+            0x0c, 0x00, 0x09, 0x00,
+            0x01, 0x00, 0x00, 0x00, // vf 1
+            0x01, 0x00, 0x00, 0x00, // on
+
+            // IFLA_VF_STATS (L=16, T=8)
+            0x10, 0x00, 0x08, 0x00,
+                // IFLA_VF_STATS_RX_PACKETS (L=12, T=0)
+                0x0c, 0x00, 0x00, 0x00,
+                0xe8, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 1000
+];
+
+#[test]
+fn parse_vf_info_list() {
+    let buf = NlaBuffer::new_checked(&VF_INFO_LIST[..]).unwrap();
+    let parsed = super::Nla::parse_with_param(&buf, AF_UNSPEC).unwrap();
+    let expected = super::Nla::VfInfoList(vec![VfInfo::Info(vec![
+        Vf::Mac(VfMac::new(1, [0x52, 0x54, 0x00, 0x12, 0x34, 0x56])),
+        Vf::Vlan(VfVlan {
+            vf: 1,
+            vlan: 100,
+            qos: 3,
+        }),
+        Vf::Trust(VfSetting { vf: 1, setting: 1 }),
+        Vf::Stats(vec![VfStats::RxPackets(1000)]),
+    ])]);
+    assert_eq!(parsed, expected);
+    assert_eq!(&get_byte_buffer(&expected)[..], &VF_INFO_LIST[..]);
+}
+
+#[test]
+fn vf_mac_padding() {
+    let mac = VfMac::new(0, [0xff; 6]);
+    assert_eq!(mac.mac[..6], [0xff; 6]);
+    assert_eq!(mac.mac[6..], [0; VF_MAC_LEN - 6]);
+}
+
+#[rustfmt::skip]
+static PROP_LIST: [u8; 36] = [
+    // IFLA_PROP_LIST (L=36, T=52)
+    0x24, 0x00, 0x34, 0x00,
+        // IFLA_ALT_IFNAME (L=21, T=53)
+        0x15, 0x00, 0x35, 0x00,
+        0x65, 0x6e, 0x70, 0x30, 0x73, 0x33, 0x31, 0x66, // enp0s31f
+        0x36, 0x2d, 0x75, 0x70, 0x6c, 0x69, 0x6e, 0x6b, // 6-uplink
+        0x00, // nul byte
+        0x00, 0x00, 0x00, // padding
+
+        // IFLA_ALT_IFNAME (L=8, T=53)
+        0x08, 0x00, 0x35, 0x00,
+        0x77, 0x61, 0x6e, 0x00, // wan
+];
+
+#[test]
+fn parse_prop_list() {
+    let buf = NlaBuffer::new_checked(&PROP_LIST[..]).unwrap();
+    let parsed = super::Nla::parse_with_param(&buf, AF_UNSPEC).unwrap();
+    let expected = super::Nla::PropList(vec![
+        Prop::AltIfName("enp0s31f6-uplink".into()),
+        Prop::AltIfName("wan".into()),
+    ]);
+    assert_eq!(parsed, expected);
+    assert_eq!(&get_byte_buffer(&expected)[..], &PROP_LIST[..]);
+}
+
+#[rustfmt::skip]
+static PROTINFO_INET6: [u8; 40] = [
+    // IFLA_PROTINFO (L=40, T=12)
+    0x28, 0x00, 0x0c, 0x00,
+        // IFLA_INET6_FLAGS (L=8, T=1)
+        0x08, 0x00, 0x01, 0x00,
+        0x20, 0x00, 0x00, 0x80, // IF_READY | IF_RA_RCVD
+
+        // IFLA_INET6_ADDR_GEN_MODE (L=5, T=8)
+        0x05, 0x00, 0x08, 0x00,
+        0x02, // IN6_ADDR_GEN_MODE_STABLE_PRIVACY
+        0x00, 0x00, 0x00, // padding
+
+        // IFLA_INET6_TOKEN (L=20, T=7)
+        0x14, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // ::1:2
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x02,
+];
+
+#[test]
+fn parse_protinfo_inet6() {
+    let buf = NlaBuffer::new_checked(&PROTINFO_INET6[..]).unwrap();
+    let parsed = super::Nla::parse_with_param(&buf, AF_INET6).unwrap();
+    let expected = super::Nla::ProtoInfoInet6(vec![
+        Inet6::Flags(IF_READY | IF_RA_RCVD),
+        Inet6::AddrGenMode(IN6_ADDR_GEN_MODE_STABLE_PRIVACY),
+        Inet6::Token([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2]),
+    ]);
+    assert_eq!(parsed, expected);
+    assert_eq!(&get_byte_buffer(&expected)[..], &PROTINFO_INET6[..]);
+}
+
+#[rustfmt::skip]
+static PROTINFO_BRIDGE: [u8; 20] = [
+    // IFLA_PROTINFO (L=20, T=12 | NLA_F_NESTED)
+    0x14, 0x00, 0x0c, 0x80,
+        // IFLA_BRPORT_STATE (L=5, T=1)
+        0x05, 0x00, 0x01, 0x00,
+        0x03, // BR_STATE_FORWARDING
+        0x00, 0x00, 0x00, // padding
+
+        // IFLA_BRPORT_COST (L=8, T=3)
+        0x08, 0x00, 0x03, 0x00,
+        0x64, 0x00, 0x00, 0x00, // 100
+];
+
+#[test]
+fn parse_protinfo_bridge() {
+    let buf = NlaBuffer::new_checked(&PROTINFO_BRIDGE[..]).unwrap();
+    let parsed = super::Nla::parse_with_param(&buf, AF_BRIDGE).unwrap();
+    let expected = super::Nla::ProtoInfoBridge(vec![
+        InfoBridgePort::State(BR_STATE_FORWARDING),
+        InfoBridgePort::Cost(100),
+    ]);
+    assert_eq!(parsed, expected);
+    assert_eq!(&get_byte_buffer(&expected)[..], &PROTINFO_BRIDGE[..]);
+}
+
+#[rustfmt::skip]
+static AF_SPEC_INET_CONF_VALUES: [u8; 28] = [
+    // IFLA_AF_SPEC (L=28, T=26)
+    0x1c, 0x00, 0x1a, 0x00,
+        // AF_INET (L=24, T=2)
+        0x18, 0x00, 0x02, 0x00,
+            // IFLA_INET_CONF (L=20, T=1)
+            0x14, 0x00, 0x01, 0x00,
+                // IPV4_DEVCONF_FORWARDING (L=8, T=1)
+                0x08, 0x00, 0x01, 0x00,
+                0x01, 0x00, 0x00, 0x00,
+                // IPV4_DEVCONF_RP_FILTER (L=8, T=8)
+                0x08, 0x00, 0x08, 0x00,
+                0x02, 0x00, 0x00, 0x00,
+];
+
+#[test]
+fn emit_af_spec_inet_conf_values() {
+    let nla = super::Nla::AfSpecInet(vec![AfSpecInet::Inet(vec![Inet::DevConfValues(vec![
+        InetDevConfValue {
+            index: IPV4_DEVCONF_FORWARDING,
+            value: 1,
+        },
+        InetDevConfValue {
+            index: IPV4_DEVCONF_RP_FILTER,
+            value: 2,
+        },
+    ])])]);
+    assert_eq!(&get_byte_buffer(&nla)[..], &AF_SPEC_INET_CONF_VALUES[..]);
+}
+
+#[test]
+fn parse_link_event() {
+    #[rustfmt::skip]
+    let event = [
+        // IFLA_EVENT (L=8, T=44)
+        0x08, 0x00, 0x2c, 0x00,
+        0x04, 0x00, 0x00, 0x00, // IFLA_EVENT_NOTIFY_PEERS
+    ];
+    let buf = NlaBuffer::new_checked(&event[..]).unwrap();
+    let parsed = super::Nla::parse_with_param(&buf, AF_UNSPEC).unwrap();
+    assert_eq!(parsed, super::Nla::Event(LinkEvent::NotifyPeers));
+    assert_eq!(&get_byte_buffer(&parsed)[..], &event[..]);
+
+    #[rustfmt::skip]
+    let carrier_up_count = [
+        // IFLA_CARRIER_UP_COUNT (L=8, T=47)
+        0x08, 0x00, 0x2f, 0x00,
+        0x03, 0x00, 0x00, 0x00, // 3
+    ];
+    let buf = NlaBuffer::new_checked(&carrier_up_count[..]).unwrap();
+    let parsed = super::Nla::parse_with_param(&buf, AF_UNSPEC).unwrap();
+    assert_eq!(parsed, super::Nla::CarrierUpCount(3));
+
+    assert_eq!(LinkEvent::from(42), LinkEvent::Other(42));
+    assert_eq!(
+        u32::from(LinkEvent::BondingOptions),
+        IFLA_EVENT_BONDING_OPTIONS
+    );
+}
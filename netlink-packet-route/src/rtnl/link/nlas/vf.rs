@@ -0,0 +1,439 @@
+use byteorder::{ByteOrder, NativeEndian};
+use failure::ResultExt;
+
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u64,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const VF_MAC_LEN: usize = 32;
+
+/// An entry of the `IFLA_VFINFO_LIST` attribute, describing a single virtual function.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum VfInfo {
+    Info(Vec<Vf>),
+    Other(DefaultNla),
+}
+
+impl Nla for VfInfo {
+    fn value_len(&self) -> usize {
+        use self::VfInfo::*;
+        match *self {
+            Info(ref nlas) => nlas.as_slice().buffer_len(),
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::VfInfo::*;
+        match *self {
+            Info(ref nlas) => nlas.as_slice().emit(buffer),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::VfInfo::*;
+        match *self {
+            Info(_) => IFLA_VF_INFO,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VfInfo {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::VfInfo::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_VF_INFO => {
+                let err = "invalid IFLA_VF_INFO value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(err)?;
+                    nlas.push(Vf::parse(&nla).context(err)?);
+                }
+                Info(nlas)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// `struct ifla_vf_mac`. Only the first bytes of `mac` are meaningful, depending on the link
+/// layer of the device (6 bytes for Ethernet).
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct VfMac {
+    pub vf: u32,
+    pub mac: [u8; VF_MAC_LEN],
+}
+
+impl VfMac {
+    /// Create a `VfMac` for the given VF from an Ethernet MAC address, padded with zeros. For
+    /// other link layers, set the `mac` field directly.
+    pub fn new(vf: u32, mac: [u8; 6]) -> Self {
+        let mut padded = [0; VF_MAC_LEN];
+        padded[..mac.len()].copy_from_slice(&mac);
+        VfMac { vf, mac: padded }
+    }
+}
+
+/// `struct ifla_vf_vlan`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct VfVlan {
+    pub vf: u32,
+    pub vlan: u32,
+    pub qos: u32,
+}
+
+/// `struct ifla_vf_tx_rate`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct VfTxRate {
+    pub vf: u32,
+    /// Maximum TX bandwidth in Mbps, 0 disables throttling
+    pub rate: u32,
+}
+
+/// `struct ifla_vf_rate`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct VfRate {
+    pub vf: u32,
+    /// Minimum TX bandwidth in Mbps
+    pub min_tx_rate: u32,
+    /// Maximum TX bandwidth in Mbps
+    pub max_tx_rate: u32,
+}
+
+/// A per-VF setting: `struct ifla_vf_spoofchk`, `struct ifla_vf_link_state`,
+/// `struct ifla_vf_rss_query_en` or `struct ifla_vf_trust`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct VfSetting {
+    pub vf: u32,
+    pub setting: u32,
+}
+
+/// `struct ifla_vf_guid`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct VfGuid {
+    pub vf: u32,
+    pub guid: u64,
+}
+
+// https://elixir.bootlin.com/linux/latest/source/include/uapi/linux/if_link.h#L700
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Vf {
+    Unspec(Vec<u8>),
+    Mac(VfMac),
+    Vlan(VfVlan),
+    TxRate(VfTxRate),
+    SpoofCheck(VfSetting),
+    /// The setting is one of the `IFLA_VF_LINK_STATE_*` constants
+    LinkState(VfSetting),
+    Rate(VfRate),
+    RssQueryEn(VfSetting),
+    Stats(Vec<VfStats>),
+    Trust(VfSetting),
+    IbNodeGuid(VfGuid),
+    IbPortGuid(VfGuid),
+    VlanList(Vec<u8>),
+    Broadcast(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl Nla for Vf {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::Vf::*;
+        match *self {
+            Unspec(ref bytes)
+                | VlanList(ref bytes)
+                | Broadcast(ref bytes)
+                => bytes.len(),
+            Mac(_) => 4 + VF_MAC_LEN,
+            Vlan(_) | Rate(_) => 12,
+            TxRate(_)
+                | SpoofCheck(_)
+                | LinkState(_)
+                | RssQueryEn(_)
+                | Trust(_)
+                => 8,
+            // the u64 is 8 bytes aligned
+            IbNodeGuid(_) | IbPortGuid(_) => 16,
+            Stats(ref nlas) => nlas.as_slice().buffer_len(),
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Vf::*;
+        match *self {
+            Unspec(ref bytes)
+                | VlanList(ref bytes)
+                | Broadcast(ref bytes)
+                => buffer.copy_from_slice(bytes.as_slice()),
+            Mac(ref mac) => {
+                NativeEndian::write_u32(&mut buffer[..4], mac.vf);
+                buffer[4..4 + VF_MAC_LEN].copy_from_slice(&mac.mac[..]);
+            }
+            Vlan(ref vlan) => {
+                NativeEndian::write_u32(&mut buffer[..4], vlan.vf);
+                NativeEndian::write_u32(&mut buffer[4..8], vlan.vlan);
+                NativeEndian::write_u32(&mut buffer[8..12], vlan.qos);
+            }
+            Rate(ref rate) => {
+                NativeEndian::write_u32(&mut buffer[..4], rate.vf);
+                NativeEndian::write_u32(&mut buffer[4..8], rate.min_tx_rate);
+                NativeEndian::write_u32(&mut buffer[8..12], rate.max_tx_rate);
+            }
+            TxRate(ref rate) => {
+                NativeEndian::write_u32(&mut buffer[..4], rate.vf);
+                NativeEndian::write_u32(&mut buffer[4..8], rate.rate);
+            }
+            SpoofCheck(ref value)
+                | LinkState(ref value)
+                | RssQueryEn(ref value)
+                | Trust(ref value)
+                => {
+                    NativeEndian::write_u32(&mut buffer[..4], value.vf);
+                    NativeEndian::write_u32(&mut buffer[4..8], value.setting);
+                }
+            IbNodeGuid(ref value)
+                | IbPortGuid(ref value)
+                => {
+                    NativeEndian::write_u32(&mut buffer[..4], value.vf);
+                    NativeEndian::write_u32(&mut buffer[4..8], 0);
+                    NativeEndian::write_u64(&mut buffer[8..16], value.guid);
+                }
+            Stats(ref nlas) => nlas.as_slice().emit(buffer),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Vf::*;
+        match *self {
+            Unspec(_) => IFLA_VF_UNSPEC,
+            Mac(_) => IFLA_VF_MAC,
+            Vlan(_) => IFLA_VF_VLAN,
+            TxRate(_) => IFLA_VF_TX_RATE,
+            SpoofCheck(_) => IFLA_VF_SPOOFCHK,
+            LinkState(_) => IFLA_VF_LINK_STATE,
+            Rate(_) => IFLA_VF_RATE,
+            RssQueryEn(_) => IFLA_VF_RSS_QUERY_EN,
+            Stats(_) => IFLA_VF_STATS,
+            Trust(_) => IFLA_VF_TRUST,
+            IbNodeGuid(_) => IFLA_VF_IB_NODE_GUID,
+            IbPortGuid(_) => IFLA_VF_IB_PORT_GUID,
+            VlanList(_) => IFLA_VF_VLAN_LIST,
+            Broadcast(_) => IFLA_VF_BROADCAST,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+fn parse_vf_setting(payload: &[u8]) -> Result<VfSetting, DecodeError> {
+    if payload.len() < 8 {
+        return Err(format!("invalid VF setting: {:?}", payload).into());
+    }
+    Ok(VfSetting {
+        vf: NativeEndian::read_u32(&payload[..4]),
+        setting: NativeEndian::read_u32(&payload[4..8]),
+    })
+}
+
+fn parse_vf_guid(payload: &[u8]) -> Result<VfGuid, DecodeError> {
+    if payload.len() < 16 {
+        return Err(format!("invalid VF GUID: {:?}", payload).into());
+    }
+    Ok(VfGuid {
+        vf: NativeEndian::read_u32(&payload[..4]),
+        guid: NativeEndian::read_u64(&payload[8..16]),
+    })
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Vf {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Vf::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_VF_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_VF_MAC => {
+                if payload.len() < 4 + VF_MAC_LEN {
+                    return Err(format!("invalid IFLA_VF_MAC value: {:?}", payload).into());
+                }
+                let mut mac = [0; VF_MAC_LEN];
+                mac.copy_from_slice(&payload[4..4 + VF_MAC_LEN]);
+                Mac(VfMac {
+                    vf: NativeEndian::read_u32(&payload[..4]),
+                    mac,
+                })
+            }
+            IFLA_VF_VLAN => {
+                if payload.len() < 12 {
+                    return Err(format!("invalid IFLA_VF_VLAN value: {:?}", payload).into());
+                }
+                Vlan(VfVlan {
+                    vf: NativeEndian::read_u32(&payload[..4]),
+                    vlan: NativeEndian::read_u32(&payload[4..8]),
+                    qos: NativeEndian::read_u32(&payload[8..12]),
+                })
+            }
+            IFLA_VF_TX_RATE => {
+                if payload.len() < 8 {
+                    return Err(format!("invalid IFLA_VF_TX_RATE value: {:?}", payload).into());
+                }
+                TxRate(VfTxRate {
+                    vf: NativeEndian::read_u32(&payload[..4]),
+                    rate: NativeEndian::read_u32(&payload[4..8]),
+                })
+            }
+            IFLA_VF_RATE => {
+                if payload.len() < 12 {
+                    return Err(format!("invalid IFLA_VF_RATE value: {:?}", payload).into());
+                }
+                Rate(VfRate {
+                    vf: NativeEndian::read_u32(&payload[..4]),
+                    min_tx_rate: NativeEndian::read_u32(&payload[4..8]),
+                    max_tx_rate: NativeEndian::read_u32(&payload[8..12]),
+                })
+            }
+            IFLA_VF_SPOOFCHK => {
+                SpoofCheck(parse_vf_setting(payload).context("invalid IFLA_VF_SPOOFCHK value")?)
+            }
+            IFLA_VF_LINK_STATE => {
+                LinkState(parse_vf_setting(payload).context("invalid IFLA_VF_LINK_STATE value")?)
+            }
+            IFLA_VF_RSS_QUERY_EN => {
+                RssQueryEn(parse_vf_setting(payload).context("invalid IFLA_VF_RSS_QUERY_EN value")?)
+            }
+            IFLA_VF_TRUST => {
+                Trust(parse_vf_setting(payload).context("invalid IFLA_VF_TRUST value")?)
+            }
+            IFLA_VF_IB_NODE_GUID => {
+                IbNodeGuid(parse_vf_guid(payload).context("invalid IFLA_VF_IB_NODE_GUID value")?)
+            }
+            IFLA_VF_IB_PORT_GUID => {
+                IbPortGuid(parse_vf_guid(payload).context("invalid IFLA_VF_IB_PORT_GUID value")?)
+            }
+            IFLA_VF_STATS => {
+                let err = "invalid IFLA_VF_STATS value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(err)?;
+                    nlas.push(VfStats::parse(&nla).context(err)?);
+                }
+                Stats(nlas)
+            }
+            IFLA_VF_VLAN_LIST => VlanList(payload.to_vec()),
+            IFLA_VF_BROADCAST => Broadcast(payload.to_vec()),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum VfStats {
+    RxPackets(u64),
+    TxPackets(u64),
+    RxBytes(u64),
+    TxBytes(u64),
+    Broadcast(u64),
+    Multicast(u64),
+    Pad(Vec<u8>),
+    RxDropped(u64),
+    TxDropped(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for VfStats {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::VfStats::*;
+        match *self {
+            Pad(ref bytes) => bytes.len(),
+            RxPackets(_)
+                | TxPackets(_)
+                | RxBytes(_)
+                | TxBytes(_)
+                | Broadcast(_)
+                | Multicast(_)
+                | RxDropped(_)
+                | TxDropped(_)
+                => 8,
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::VfStats::*;
+        match *self {
+            Pad(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            RxPackets(ref value)
+                | TxPackets(ref value)
+                | RxBytes(ref value)
+                | TxBytes(ref value)
+                | Broadcast(ref value)
+                | Multicast(ref value)
+                | RxDropped(ref value)
+                | TxDropped(ref value)
+                => NativeEndian::write_u64(buffer, *value),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::VfStats::*;
+        match *self {
+            RxPackets(_) => IFLA_VF_STATS_RX_PACKETS,
+            TxPackets(_) => IFLA_VF_STATS_TX_PACKETS,
+            RxBytes(_) => IFLA_VF_STATS_RX_BYTES,
+            TxBytes(_) => IFLA_VF_STATS_TX_BYTES,
+            Broadcast(_) => IFLA_VF_STATS_BROADCAST,
+            Multicast(_) => IFLA_VF_STATS_MULTICAST,
+            Pad(_) => IFLA_VF_STATS_PAD,
+            RxDropped(_) => IFLA_VF_STATS_RX_DROPPED,
+            TxDropped(_) => IFLA_VF_STATS_TX_DROPPED,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VfStats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::VfStats::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_VF_STATS_RX_PACKETS => {
+                RxPackets(parse_u64(payload).context("invalid IFLA_VF_STATS_RX_PACKETS value")?)
+            }
+            IFLA_VF_STATS_TX_PACKETS => {
+                TxPackets(parse_u64(payload).context("invalid IFLA_VF_STATS_TX_PACKETS value")?)
+            }
+            IFLA_VF_STATS_RX_BYTES => {
+                RxBytes(parse_u64(payload).context("invalid IFLA_VF_STATS_RX_BYTES value")?)
+            }
+            IFLA_VF_STATS_TX_BYTES => {
+                TxBytes(parse_u64(payload).context("invalid IFLA_VF_STATS_TX_BYTES value")?)
+            }
+            IFLA_VF_STATS_BROADCAST => {
+                Broadcast(parse_u64(payload).context("invalid IFLA_VF_STATS_BROADCAST value")?)
+            }
+            IFLA_VF_STATS_MULTICAST => {
+                Multicast(parse_u64(payload).context("invalid IFLA_VF_STATS_MULTICAST value")?)
+            }
+            IFLA_VF_STATS_PAD => Pad(payload.to_vec()),
+            IFLA_VF_STATS_RX_DROPPED => {
+                RxDropped(parse_u64(payload).context("invalid IFLA_VF_STATS_RX_DROPPED value")?)
+            }
+            IFLA_VF_STATS_TX_DROPPED => {
+                TxDropped(parse_u64(payload).context("invalid IFLA_VF_STATS_TX_DROPPED value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
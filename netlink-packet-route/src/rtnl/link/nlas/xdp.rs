@@ -0,0 +1,117 @@
+use std::os::unix::io::RawFd;
+
+use byteorder::{ByteOrder, NativeEndian};
+use failure::ResultExt;
+
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_i32, parse_u32, parse_u8},
+    traits::Parseable,
+    DecodeError,
+};
+
+// https://elixir.bootlin.com/linux/latest/source/net/core/rtnetlink.c#L1434
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Xdp {
+    Unspec(Vec<u8>),
+    /// File descriptor of the BPF program to attach, or `-1` to detach the current program
+    Fd(RawFd),
+    /// How the program is attached: one of the `XDP_ATTACHED_*` constants
+    Attached(u8),
+    /// Combination of `XDP_FLAGS_*` constants
+    Flags(u32),
+    ProgId(u32),
+    DrvProgId(u32),
+    SkbProgId(u32),
+    HwProgId(u32),
+    /// File descriptor of the program expected to be attached, used with `XDP_FLAGS_REPLACE`
+    ExpectedFd(RawFd),
+    Other(DefaultNla),
+}
+
+impl Nla for Xdp {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::Xdp::*;
+        match *self {
+            Unspec(ref bytes) => bytes.len(),
+            Attached(_) => 1,
+            Fd(_)
+                | Flags(_)
+                | ProgId(_)
+                | DrvProgId(_)
+                | SkbProgId(_)
+                | HwProgId(_)
+                | ExpectedFd(_)
+                => 4,
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Xdp::*;
+        match *self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Attached(ref value) => buffer[0] = *value,
+            Fd(ref value)
+                | ExpectedFd(ref value)
+                => NativeEndian::write_i32(buffer, *value),
+            Flags(ref value)
+                | ProgId(ref value)
+                | DrvProgId(ref value)
+                | SkbProgId(ref value)
+                | HwProgId(ref value)
+                => NativeEndian::write_u32(buffer, *value),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Xdp::*;
+        match *self {
+            Unspec(_) => IFLA_XDP_UNSPEC,
+            Fd(_) => IFLA_XDP_FD,
+            Attached(_) => IFLA_XDP_ATTACHED,
+            Flags(_) => IFLA_XDP_FLAGS,
+            ProgId(_) => IFLA_XDP_PROG_ID,
+            DrvProgId(_) => IFLA_XDP_DRV_PROG_ID,
+            SkbProgId(_) => IFLA_XDP_SKB_PROG_ID,
+            HwProgId(_) => IFLA_XDP_HW_PROG_ID,
+            ExpectedFd(_) => IFLA_XDP_EXPECTED_FD,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Xdp {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Xdp::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_XDP_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_XDP_FD => Fd(parse_i32(payload).context("invalid IFLA_XDP_FD value")?),
+            IFLA_XDP_ATTACHED => {
+                Attached(parse_u8(payload).context("invalid IFLA_XDP_ATTACHED value")?)
+            }
+            IFLA_XDP_FLAGS => Flags(parse_u32(payload).context("invalid IFLA_XDP_FLAGS value")?),
+            IFLA_XDP_PROG_ID => {
+                ProgId(parse_u32(payload).context("invalid IFLA_XDP_PROG_ID value")?)
+            }
+            IFLA_XDP_DRV_PROG_ID => {
+                DrvProgId(parse_u32(payload).context("invalid IFLA_XDP_DRV_PROG_ID value")?)
+            }
+            IFLA_XDP_SKB_PROG_ID => {
+                SkbProgId(parse_u32(payload).context("invalid IFLA_XDP_SKB_PROG_ID value")?)
+            }
+            IFLA_XDP_HW_PROG_ID => {
+                HwProgId(parse_u32(payload).context("invalid IFLA_XDP_HW_PROG_ID value")?)
+            }
+            IFLA_XDP_EXPECTED_FD => {
+                ExpectedFd(parse_i32(payload).context("invalid IFLA_XDP_EXPECTED_FD value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
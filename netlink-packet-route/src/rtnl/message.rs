@@ -5,7 +5,7 @@ use crate::{
     traits::{Emitable, ParseableParametrized},
     AddressMessage, DecodeError, LinkMessage, NeighbourMessage, NeighbourTableMessage,
     NetlinkDeserializable, NetlinkHeader, NetlinkPayload, NetlinkSerializable, NsidMessage,
-    RouteMessage, RtnlMessageBuffer, RuleMessage, TcMessage,
+    RouteMessage, RtnlMessageBuffer, RuleMessage, StatsMessage, TcMessage,
 };
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -14,6 +14,9 @@ pub enum RtnlMessage {
     DelLink(LinkMessage),
     GetLink(LinkMessage),
     SetLink(LinkMessage),
+    NewLinkProp(LinkMessage),
+    DelLinkProp(LinkMessage),
+    GetLinkProp(LinkMessage),
     NewAddress(AddressMessage),
     DelAddress(AddressMessage),
     GetAddress(AddressMessage),
@@ -41,6 +44,8 @@ pub enum RtnlMessage {
     NewRule(RuleMessage),
     DelRule(RuleMessage),
     GetRule(RuleMessage),
+    NewStats(StatsMessage),
+    GetStats(StatsMessage),
 }
 
 impl RtnlMessage {
@@ -76,6 +81,18 @@ impl RtnlMessage {
         }
     }
 
+    pub fn is_new_link_prop(&self) -> bool {
+        matches!(*self, RtnlMessage::NewLinkProp(_))
+    }
+
+    pub fn is_del_link_prop(&self) -> bool {
+        matches!(*self, RtnlMessage::DelLinkProp(_))
+    }
+
+    pub fn is_get_link_prop(&self) -> bool {
+        matches!(*self, RtnlMessage::GetLinkProp(_))
+    }
+
     pub fn is_new_address(&self) -> bool {
         if let RtnlMessage::NewAddress(_) = *self {
             true
@@ -292,6 +309,14 @@ impl RtnlMessage {
         }
     }
 
+    pub fn is_new_stats(&self) -> bool {
+        matches!(*self, RtnlMessage::NewStats(_))
+    }
+
+    pub fn is_get_stats(&self) -> bool {
+        matches!(*self, RtnlMessage::GetStats(_))
+    }
+
     pub fn message_type(&self) -> u16 {
         use self::RtnlMessage::*;
 
@@ -300,6 +325,9 @@ impl RtnlMessage {
             DelLink(_) => RTM_DELLINK,
             GetLink(_) => RTM_GETLINK,
             SetLink(_) => RTM_SETLINK,
+            NewLinkProp(_) => RTM_NEWLINKPROP,
+            DelLinkProp(_) => RTM_DELLINKPROP,
+            GetLinkProp(_) => RTM_GETLINKPROP,
             NewAddress(_) => RTM_NEWADDR,
             DelAddress(_) => RTM_DELADDR,
             GetAddress(_) => RTM_GETADDR,
@@ -327,6 +355,8 @@ impl RtnlMessage {
             GetRule(_) => RTM_GETRULE,
             NewRule(_) => RTM_NEWRULE,
             DelRule(_) => RTM_DELRULE,
+            NewStats(_) => RTM_NEWSTATS,
+            GetStats(_) => RTM_GETSTATS,
         }
     }
 }
@@ -340,6 +370,9 @@ impl Emitable for RtnlMessage {
             | DelLink(ref msg)
             | GetLink(ref msg)
             | SetLink(ref msg)
+            | NewLinkProp(ref msg)
+            | DelLinkProp(ref msg)
+            | GetLinkProp(ref msg)
             =>  msg.buffer_len(),
 
             | NewAddress(ref msg)
@@ -381,6 +414,10 @@ impl Emitable for RtnlMessage {
             | NewRule(ref msg)
             | DelRule(ref msg)
             | GetRule(ref msg)
+            => msg.buffer_len(),
+
+            | NewStats(ref msg)
+            | GetStats(ref msg)
             => msg.buffer_len()
         }
     }
@@ -393,6 +430,9 @@ impl Emitable for RtnlMessage {
             | DelLink(ref msg)
             | GetLink(ref msg)
             | SetLink(ref msg)
+            | NewLinkProp(ref msg)
+            | DelLinkProp(ref msg)
+            | GetLinkProp(ref msg)
             => msg.emit(buffer),
 
             | NewAddress(ref msg)
@@ -434,6 +474,10 @@ impl Emitable for RtnlMessage {
             | NewRule(ref msg)
             | DelRule(ref msg)
             | GetRule(ref msg)
+            => msg.emit(buffer),
+
+            | NewStats(ref msg)
+            | GetStats(ref msg)
             => msg.emit(buffer)
         }
     }
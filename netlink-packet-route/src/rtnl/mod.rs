@@ -24,6 +24,9 @@ pub use route::{RouteFlags, RouteHeader, RouteMessage, RouteMessageBuffer, ROUTE
 pub mod rule;
 pub use rule::{RuleHeader, RuleMessage, RuleMessageBuffer, RULE_HEADER_LEN};
 
+pub mod stats;
+pub use stats::{StatsHeader, StatsMessage, StatsMessageBuffer, STATS_HEADER_LEN};
+
 pub mod tc;
 pub use tc::{TcHeader, TcMessage, TcMessageBuffer, TC_HEADER_LEN};
 
@@ -44,6 +47,7 @@ pub mod nlas {
     pub use super::nsid::nlas as nsid;
     pub use super::route::nlas as route;
     pub use super::rule::nlas as rule;
+    pub use super::stats::nlas as stats;
     pub use super::tc::nlas as tc;
     pub use crate::utils::nla::*;
 }
@@ -0,0 +1,55 @@
+use super::{StatsMessageBuffer, STATS_HEADER_LEN};
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// High level representation of `RTM_GETSTATS` and `RTM_NEWSTATS` messages headers.
+///
+/// These headers have the following structure:
+///
+/// ```no_rust
+/// 0                8                16              24               32
+/// +----------------+----------------+----------------+----------------+
+/// |     family     |    padding     |             padding             |
+/// +----------------+----------------+----------------+----------------+
+/// |                             link index                            |
+/// +----------------+----------------+----------------+----------------+
+/// |                            filter mask                            |
+/// +----------------+----------------+----------------+----------------+
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct StatsHeader {
+    /// Address family. It is usually `AF_UNSPEC`.
+    pub family: u8,
+    /// Link index. In a dump request, `0` means all the links.
+    pub index: u32,
+    /// Combination of `IFLA_STATS_FILTER_*` constants, selecting the `IFLA_STATS_*` attributes
+    /// the kernel should report.
+    pub filter_mask: u32,
+}
+
+impl Emitable for StatsHeader {
+    fn buffer_len(&self) -> usize {
+        STATS_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = StatsMessageBuffer::new(buffer);
+        packet.set_family(self.family);
+        packet.set_pad1(0);
+        packet.set_pad2(0);
+        packet.set_ifindex(self.index);
+        packet.set_filter_mask(self.filter_mask);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<StatsMessageBuffer<T>> for StatsHeader {
+    fn parse(buf: &StatsMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(StatsHeader {
+            family: buf.family(),
+            index: buf.ifindex(),
+            filter_mask: buf.filter_mask(),
+        })
+    }
+}
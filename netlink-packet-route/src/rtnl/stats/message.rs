@@ -0,0 +1,139 @@
+use failure::ResultExt;
+
+use crate::{
+    nlas::stats::Nla,
+    traits::{Emitable, Parseable},
+    DecodeError, StatsHeader, StatsMessageBuffer,
+};
+
+/// High level representation of `RTM_GETSTATS` and `RTM_NEWSTATS` messages.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct StatsMessage {
+    pub header: StatsHeader,
+    pub nlas: Vec<Nla>,
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<StatsMessageBuffer<&'a T>> for StatsMessage {
+    fn parse(buf: &StatsMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: StatsHeader::parse(buf).context("failed to parse stats message header")?,
+            nlas: Vec::<Nla>::parse(buf).context("failed to parse stats message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<StatsMessageBuffer<&'a T>> for Vec<Nla> {
+    fn parse(buf: &StatsMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        for nla_buf in buf.nlas() {
+            nlas.push(Nla::parse(&nla_buf?)?);
+        }
+        Ok(nlas)
+    }
+}
+
+impl Emitable for StatsMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        nlas::stats::{AfSpecStats, MplsLinkStats, MplsStats, Nla},
+        traits::{Emitable, ParseableParametrized},
+        NetlinkBuffer, RtnlMessage, RtnlMessageBuffer, StatsHeader, StatsMessage,
+        IFLA_STATS_FILTER_AF_SPEC, IFLA_STATS_FILTER_LINK_64, RTM_GETSTATS, RTM_NEWSTATS,
+    };
+
+    #[rustfmt::skip]
+    #[test]
+    fn get_stats_request() {
+        let data = vec![
+            0x1c, 0x00, 0x00, 0x00, // length = 28
+            0x5e, 0x00, // message type = 94 = RTM_GETSTATS
+            0x01, 0x03, // flags
+            0x00, 0x00, 0x00, 0x00, // seq number
+            0x00, 0x00, 0x00, 0x00, // pid
+
+            // GETSTATS message
+            0x00, // family
+            0x00, 0x00, 0x00, // padding
+            0x00, 0x00, 0x00, 0x00, // ifindex
+            0x01, 0x00, 0x00, 0x00, // filter mask = IFLA_STATS_FILTER_LINK_64
+        ];
+        let expected = RtnlMessage::GetStats(StatsMessage {
+            header: StatsHeader {
+                family: 0,
+                index: 0,
+                filter_mask: IFLA_STATS_FILTER_LINK_64,
+            },
+            nlas: vec![],
+        });
+        let nl_buffer = NetlinkBuffer::new(&data).payload();
+        let rtnl_buffer = RtnlMessageBuffer::new(&nl_buffer);
+        let actual = RtnlMessage::parse_with_param(&rtnl_buffer, RTM_GETSTATS).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn new_stats_af_spec() {
+        let data = vec![
+            // NEWSTATS message
+            0x00, // family
+            0x00, 0x00, 0x00, // padding
+            0x02, 0x00, 0x00, 0x00, // ifindex = 2
+            0x10, 0x00, 0x00, 0x00, // filter mask = IFLA_STATS_FILTER_AF_SPEC
+
+            // IFLA_STATS_AF_SPEC (L=84, T=5)
+            0x54, 0x00, 0x05, 0x00,
+                // AF_MPLS (L=80, T=28)
+                0x50, 0x00, 0x1c, 0x00,
+                    // MPLS_STATS_LINK (L=76, T=1)
+                    0x4c, 0x00, 0x01, 0x00,
+                    0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_packets = 10
+                    0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_packets = 20
+                    0xe8, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_bytes = 1000
+                    0xd0, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_bytes = 2000
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_errors
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_errors
+                    0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_dropped = 1
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_dropped
+                    0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_noroute = 3
+        ];
+        let expected = RtnlMessage::NewStats(StatsMessage {
+            header: StatsHeader {
+                family: 0,
+                index: 2,
+                filter_mask: IFLA_STATS_FILTER_AF_SPEC,
+            },
+            nlas: vec![Nla::AfSpec(vec![AfSpecStats::Mpls(vec![MplsStats::Link(
+                MplsLinkStats {
+                    rx_packets: 10,
+                    tx_packets: 20,
+                    rx_bytes: 1000,
+                    tx_bytes: 2000,
+                    rx_dropped: 1,
+                    rx_noroute: 3,
+                    ..Default::default()
+                },
+            )])])],
+        });
+        let actual =
+            RtnlMessage::parse_with_param(&RtnlMessageBuffer::new(&data), RTM_NEWSTATS).unwrap();
+        assert_eq!(expected, actual);
+
+        let mut buf = vec![0xff; expected.buffer_len()];
+        expected.emit(&mut buf);
+        assert_eq!(buf, data);
+    }
+}
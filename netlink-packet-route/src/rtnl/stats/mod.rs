@@ -0,0 +1,9 @@
+mod buffer;
+mod header;
+mod message;
+pub mod nlas;
+
+pub use self::buffer::*;
+pub use self::header::*;
+pub use self::message::*;
+pub use self::nlas::*;
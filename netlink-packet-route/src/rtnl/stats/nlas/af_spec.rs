@@ -0,0 +1,183 @@
+use failure::ResultExt;
+
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Per address family statistics, found in the `IFLA_STATS_AF_SPEC` attribute.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum AfSpecStats {
+    Mpls(Vec<MplsStats>),
+    Other(DefaultNla),
+}
+
+impl Nla for AfSpecStats {
+    fn value_len(&self) -> usize {
+        use self::AfSpecStats::*;
+        match *self {
+            Mpls(ref nlas) => nlas.as_slice().buffer_len(),
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::AfSpecStats::*;
+        match *self {
+            Mpls(ref nlas) => nlas.as_slice().emit(buffer),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::AfSpecStats::*;
+        match *self {
+            Mpls(_) => AF_MPLS,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for AfSpecStats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::AfSpecStats::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            AF_MPLS => {
+                let err = "invalid AF_MPLS value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(err)?;
+                    nlas.push(MplsStats::parse(&nla).context(err)?);
+                }
+                Mpls(nlas)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MplsStats {
+    Unspec(Vec<u8>),
+    Link(MplsLinkStats),
+    Other(DefaultNla),
+}
+
+impl Nla for MplsStats {
+    fn value_len(&self) -> usize {
+        use self::MplsStats::*;
+        match *self {
+            Unspec(ref bytes) => bytes.len(),
+            Link(ref stats) => stats.buffer_len(),
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::MplsStats::*;
+        match *self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Link(ref stats) => stats.emit(buffer),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::MplsStats::*;
+        match *self {
+            Unspec(_) => MPLS_STATS_UNSPEC,
+            Link(_) => MPLS_STATS_LINK,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MplsStats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::MplsStats::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MPLS_STATS_UNSPEC => Unspec(payload.to_vec()),
+            MPLS_STATS_LINK => {
+                let err = "invalid MPLS_STATS_LINK value";
+                let buf = MplsLinkStatsBuffer::new_checked(payload).context(err)?;
+                Link(MplsLinkStats::parse(&buf).context(err)?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+pub const MPLS_LINK_STATS_LEN: usize = 72;
+
+buffer!(MplsLinkStatsBuffer(MPLS_LINK_STATS_LEN) {
+    rx_packets: (u64, 0..8),
+    tx_packets: (u64, 8..16),
+    rx_bytes: (u64, 16..24),
+    tx_bytes: (u64, 24..32),
+    rx_errors: (u64, 32..40),
+    tx_errors: (u64, 40..48),
+    rx_dropped: (u64, 48..56),
+    tx_dropped: (u64, 56..64),
+    rx_noroute: (u64, 64..72),
+});
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct MplsLinkStats {
+    /// total packets received
+    pub rx_packets: u64,
+    /// total packets transmitted
+    pub tx_packets: u64,
+    /// total bytes received
+    pub rx_bytes: u64,
+    /// total bytes transmitted
+    pub tx_bytes: u64,
+    /// bad packets received
+    pub rx_errors: u64,
+    /// packet transmit problems
+    pub tx_errors: u64,
+    /// packets dropped on receipt
+    pub rx_dropped: u64,
+    /// packets dropped on transmission
+    pub tx_dropped: u64,
+    /// packets received with an unknown route
+    pub rx_noroute: u64,
+}
+
+impl<T: AsRef<[u8]>> Parseable<MplsLinkStatsBuffer<T>> for MplsLinkStats {
+    fn parse(buf: &MplsLinkStatsBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rx_packets: buf.rx_packets(),
+            tx_packets: buf.tx_packets(),
+            rx_bytes: buf.rx_bytes(),
+            tx_bytes: buf.tx_bytes(),
+            rx_errors: buf.rx_errors(),
+            tx_errors: buf.tx_errors(),
+            rx_dropped: buf.rx_dropped(),
+            tx_dropped: buf.tx_dropped(),
+            rx_noroute: buf.rx_noroute(),
+        })
+    }
+}
+
+impl Emitable for MplsLinkStats {
+    fn buffer_len(&self) -> usize {
+        MPLS_LINK_STATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = MplsLinkStatsBuffer::new(buffer);
+        buffer.set_rx_packets(self.rx_packets);
+        buffer.set_tx_packets(self.tx_packets);
+        buffer.set_rx_bytes(self.rx_bytes);
+        buffer.set_tx_bytes(self.tx_bytes);
+        buffer.set_rx_errors(self.rx_errors);
+        buffer.set_tx_errors(self.tx_errors);
+        buffer.set_rx_dropped(self.rx_dropped);
+        buffer.set_tx_dropped(self.tx_dropped);
+        buffer.set_rx_noroute(self.rx_noroute);
+    }
+}
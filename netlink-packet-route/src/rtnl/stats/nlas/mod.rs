@@ -0,0 +1,126 @@
+mod xstats;
+pub use self::xstats::*;
+
+mod af_spec;
+pub use self::af_spec::*;
+
+use failure::ResultExt;
+
+use crate::{
+    constants::*,
+    nlas::{
+        self,
+        link::{Stats64, Stats64Buffer},
+        DefaultNla, NlaBuffer, NlasIterator,
+    },
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    Link64(Stats64),
+    LinkXstats(Vec<LinkXstats>),
+    LinkXstatsSlave(Vec<LinkXstats>),
+    LinkOffloadXstats(Vec<OffloadXstats>),
+    AfSpec(Vec<AfSpecStats>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match *self {
+            Unspec(ref bytes) => bytes.len(),
+            Link64(ref stats) => stats.buffer_len(),
+            LinkXstats(ref nlas)
+                | LinkXstatsSlave(ref nlas)
+                => nlas.as_slice().buffer_len(),
+            LinkOffloadXstats(ref nlas) => nlas.as_slice().buffer_len(),
+            AfSpec(ref nlas) => nlas.as_slice().buffer_len(),
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match *self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Link64(ref stats) => stats.emit(buffer),
+            LinkXstats(ref nlas)
+                | LinkXstatsSlave(ref nlas)
+                => nlas.as_slice().emit(buffer),
+            LinkOffloadXstats(ref nlas) => nlas.as_slice().emit(buffer),
+            AfSpec(ref nlas) => nlas.as_slice().emit(buffer),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match *self {
+            Unspec(_) => IFLA_STATS_UNSPEC,
+            Link64(_) => IFLA_STATS_LINK_64,
+            LinkXstats(_) => IFLA_STATS_LINK_XSTATS,
+            LinkXstatsSlave(_) => IFLA_STATS_LINK_XSTATS_SLAVE,
+            LinkOffloadXstats(_) => IFLA_STATS_LINK_OFFLOAD_XSTATS,
+            AfSpec(_) => IFLA_STATS_AF_SPEC,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_STATS_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_STATS_LINK_64 => {
+                let err = "invalid IFLA_STATS_LINK_64 value";
+                let buf = Stats64Buffer::new_checked(payload).context(err)?;
+                Link64(Stats64::parse(&buf).context(err)?)
+            }
+            IFLA_STATS_LINK_XSTATS => {
+                let err = "invalid IFLA_STATS_LINK_XSTATS value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(err)?;
+                    nlas.push(xstats::LinkXstats::parse(&nla).context(err)?);
+                }
+                LinkXstats(nlas)
+            }
+            IFLA_STATS_LINK_XSTATS_SLAVE => {
+                let err = "invalid IFLA_STATS_LINK_XSTATS_SLAVE value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(err)?;
+                    nlas.push(xstats::LinkXstats::parse(&nla).context(err)?);
+                }
+                LinkXstatsSlave(nlas)
+            }
+            IFLA_STATS_LINK_OFFLOAD_XSTATS => {
+                let err = "invalid IFLA_STATS_LINK_OFFLOAD_XSTATS value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(err)?;
+                    nlas.push(OffloadXstats::parse(&nla).context(err)?);
+                }
+                LinkOffloadXstats(nlas)
+            }
+            IFLA_STATS_AF_SPEC => {
+                let err = "invalid IFLA_STATS_AF_SPEC value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(err)?;
+                    nlas.push(AfSpecStats::parse(&nla).context(err)?);
+                }
+                AfSpec(nlas)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
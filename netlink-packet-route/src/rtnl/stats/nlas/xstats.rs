@@ -0,0 +1,124 @@
+use failure::ResultExt;
+
+use crate::{
+    constants::*,
+    nlas::{
+        link::{Stats64, Stats64Buffer},
+        DefaultNla, Nla, NlaBuffer,
+    },
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Link type specific statistics, found in the `IFLA_STATS_LINK_XSTATS` and
+/// `IFLA_STATS_LINK_XSTATS_SLAVE` attributes.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum LinkXstats {
+    Unspec(Vec<u8>),
+    Bridge(Vec<u8>),
+    Bond(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl Nla for LinkXstats {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::LinkXstats::*;
+        match *self {
+            Unspec(ref bytes)
+                | Bridge(ref bytes)
+                | Bond(ref bytes)
+                => bytes.len(),
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::LinkXstats::*;
+        match *self {
+            Unspec(ref bytes)
+                | Bridge(ref bytes)
+                | Bond(ref bytes)
+                => buffer.copy_from_slice(bytes.as_slice()),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::LinkXstats::*;
+        match *self {
+            Unspec(_) => LINK_XSTATS_TYPE_UNSPEC,
+            Bridge(_) => LINK_XSTATS_TYPE_BRIDGE,
+            Bond(_) => LINK_XSTATS_TYPE_BOND,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for LinkXstats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::LinkXstats::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LINK_XSTATS_TYPE_UNSPEC => Unspec(payload.to_vec()),
+            LINK_XSTATS_TYPE_BRIDGE => Bridge(payload.to_vec()),
+            LINK_XSTATS_TYPE_BOND => Bond(payload.to_vec()),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Hardware offload statistics, found in the `IFLA_STATS_LINK_OFFLOAD_XSTATS` attribute.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum OffloadXstats {
+    Unspec(Vec<u8>),
+    /// Statistics of the packets that were not offloaded and hit the CPU
+    CpuHit(Stats64),
+    Other(DefaultNla),
+}
+
+impl Nla for OffloadXstats {
+    fn value_len(&self) -> usize {
+        use self::OffloadXstats::*;
+        match *self {
+            Unspec(ref bytes) => bytes.len(),
+            CpuHit(ref stats) => stats.buffer_len(),
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::OffloadXstats::*;
+        match *self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            CpuHit(ref stats) => stats.emit(buffer),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::OffloadXstats::*;
+        match *self {
+            Unspec(_) => IFLA_OFFLOAD_XSTATS_UNSPEC,
+            CpuHit(_) => IFLA_OFFLOAD_XSTATS_CPU_HIT,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for OffloadXstats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::OffloadXstats::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_OFFLOAD_XSTATS_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_OFFLOAD_XSTATS_CPU_HIT => {
+                let err = "invalid IFLA_OFFLOAD_XSTATS_CPU_HIT value";
+                let buf = Stats64Buffer::new_checked(payload).context(err)?;
+                CpuHit(Stats64::parse(&buf).context(err)?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
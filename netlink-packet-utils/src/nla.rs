@@ -191,6 +191,12 @@ pub trait Nla {
     fn value_len(&self) -> usize;
     fn kind(&self) -> u16;
     fn emit_value(&self, buffer: &mut [u8]);
+
+    /// Whether the `NLA_F_NESTED` flag should be set when emitting this attribute. Some kernel
+    /// subsystems rely on this flag to tell nested attributes from legacy payloads.
+    fn is_nested(&self) -> bool {
+        false
+    }
 }
 
 impl<T: Nla> Emitable for T {
@@ -201,6 +207,9 @@ impl<T: Nla> Emitable for T {
     fn emit(&self, buffer: &mut [u8]) {
         let mut buffer = NlaBuffer::new(buffer);
         buffer.set_kind(self.kind());
+        if self.is_nested() {
+            buffer.set_nested_flag();
+        }
         // do not include the padding here, but do include the header
         buffer.set_length(self.value_len() as u16 + 4);
         self.emit_value(buffer.value_mut());
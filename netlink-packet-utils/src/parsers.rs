@@ -1,6 +1,6 @@
 use std::mem::size_of;
 
-use byteorder::{ByteOrder, NativeEndian};
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
 use failure::ResultExt;
 
 use crate::DecodeError;
@@ -69,6 +69,27 @@ pub fn parse_u16(payload: &[u8]) -> Result<u16, DecodeError> {
     Ok(NativeEndian::read_u16(payload))
 }
 
+pub fn parse_u16_be(payload: &[u8]) -> Result<u16, DecodeError> {
+    if payload.len() != size_of::<u16>() {
+        return Err(format!("invalid u16: {:?}", payload).into());
+    }
+    Ok(BigEndian::read_u16(payload))
+}
+
+pub fn parse_u32_be(payload: &[u8]) -> Result<u32, DecodeError> {
+    if payload.len() != size_of::<u32>() {
+        return Err(format!("invalid u32: {:?}", payload).into());
+    }
+    Ok(BigEndian::read_u32(payload))
+}
+
+pub fn parse_u64_be(payload: &[u8]) -> Result<u64, DecodeError> {
+    if payload.len() != size_of::<u64>() {
+        return Err(format!("invalid u64: {:?}", payload).into());
+    }
+    Ok(BigEndian::read_u64(payload))
+}
+
 pub fn parse_i32(payload: &[u8]) -> Result<i32, DecodeError> {
     if payload.len() != 4 {
         return Err(format!("invalid u32: {:?}", payload).into());
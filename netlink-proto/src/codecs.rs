@@ -154,10 +154,10 @@ where
     fn encode(&mut self, msg: Self::Item, buf: &mut BytesMut) -> Result<(), Self::Error> {
         let msg_len = msg.buffer_len();
         // FIXME: we should have a max length for the buffer
-        while buf.remaining_mut() < msg_len {
-            let new_len = buf.len() + 2048;
-            buf.resize(new_len, 0);
-        }
+        //
+        // The buffer may already contain messages (see batches), so we
+        // must only grow its capacity, not its length.
+        buf.reserve(msg_len);
         let size = msg.buffer_len();
         if buf.remaining_mut() < size {
             return Err(io::Error::new(
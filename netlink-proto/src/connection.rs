@@ -1,22 +1,200 @@
 use std::{
+    collections::VecDeque,
     fmt::Debug,
     io,
     pin::Pin,
     task::{Context, Poll},
+    time::{Duration, Instant},
 };
 
 use futures::{
-    channel::mpsc::{UnboundedReceiver, UnboundedSender},
+    channel::mpsc::{Sender, UnboundedReceiver, UnboundedSender},
     Future, Sink, Stream,
 };
 use log::{error, warn};
 use netlink_packet_core::{
-    NetlinkDeserializable, NetlinkMessage, NetlinkPayload, NetlinkSerializable,
+    NetlinkDeserializable, NetlinkHeader, NetlinkMessage, NetlinkPayload, NetlinkSerializable,
+    NLMSG_OVERRUN,
 };
 use netlink_sys::{Protocol as NetlinkProtocol, Socket, SocketAddr};
+use tokio::time::{delay_until, Delay};
 
 use crate::{codecs::NetlinkCodec, framed::NetlinkFramed, Protocol, Request, Response};
 
+/// Maximum size of a datagram packing a batch of messages. The kernel rejects datagrams larger
+/// than the socket send buffer, which is usually much bigger than this.
+const MAX_BATCH_SIZE: usize = 32 * 1024;
+
+/// Size and destination of the batch of messages currently being packed in a datagram by
+/// `Connection::poll_send_messages`.
+#[derive(Debug, Default)]
+struct Batch(Option<(usize, SocketAddr)>);
+
+impl Batch {
+    /// Whether a message of `len` bytes for `addr` can be appended to the datagram being packed.
+    /// Only batched messages for the same destination are packed together, up to
+    /// `MAX_BATCH_SIZE` bytes.
+    fn can_append(&self, len: usize, addr: &SocketAddr, batched: bool) -> bool {
+        match self.0 {
+            Some((batch_len, batch_addr)) => {
+                batched && batch_addr == *addr && batch_len + len <= MAX_BATCH_SIZE
+            }
+            None => false,
+        }
+    }
+
+    /// Record that a message of `len` bytes for `addr` was added to the datagram being packed,
+    /// or started a new one if it could not be appended.
+    fn push(&mut self, len: usize, addr: SocketAddr, batched: bool) {
+        self.0 = match self.0 {
+            Some((batch_len, _)) if self.can_append(len, &addr, batched) => {
+                Some((batch_len + len, addr))
+            }
+            _ if batched => Some((len, addr)),
+            _ => None,
+        };
+    }
+}
+
+/// What a bounded connection does with unsollicited messages when the channel they are forwarded
+/// to is full, ie when the consumer does not keep up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Stop reading from the socket until the consumer catches up. Note that responses to
+    /// pending requests are not read either in the meantime, and that the kernel may end up
+    /// dropping messages once the socket receive buffer is full.
+    Block,
+    /// Keep up to `capacity` messages waiting for room in the channel, and drop the oldest ones
+    /// beyond that.
+    DropOldest,
+    /// Drop the messages that do not fit in the channel. Once there is room again, the consumer
+    /// receives a message with a `NetlinkPayload::Overrun` payload, telling it that it missed
+    /// some messages and should probably resynchronize its state. This is the same message that
+    /// is sent when the socket receive buffer overflows.
+    Overrun,
+}
+
+/// Channel used to forward the unsollicited messages to the user.
+pub(crate) enum UnsollicitedMessagesSender<T>
+where
+    T: Debug + Clone + PartialEq + Eq,
+{
+    Unbounded(UnboundedSender<(NetlinkMessage<T>, SocketAddr)>),
+    Bounded {
+        tx: Sender<(NetlinkMessage<T>, SocketAddr)>,
+        capacity: usize,
+        policy: OverflowPolicy,
+        /// Whether messages have been dropped since the last overrun message was sent
+        overrun: bool,
+    },
+}
+
+impl<T> UnsollicitedMessagesSender<T>
+where
+    T: Debug + Clone + PartialEq + Eq,
+{
+    pub(crate) fn bounded(
+        tx: Sender<(NetlinkMessage<T>, SocketAddr)>,
+        capacity: usize,
+        policy: OverflowPolicy,
+    ) -> Self {
+        UnsollicitedMessagesSender::Bounded {
+            tx,
+            capacity,
+            policy,
+            overrun: false,
+        }
+    }
+
+    /// Whether the socket should not be read for now
+    fn is_blocked(&self, backlog: usize) -> bool {
+        match self {
+            UnsollicitedMessagesSender::Bounded {
+                capacity,
+                policy: OverflowPolicy::Block,
+                ..
+            } => backlog >= *capacity,
+            _ => false,
+        }
+    }
+
+    /// Forward as many messages from `messages` as possible. An error is returned if the
+    /// receiver was dropped.
+    fn forward(
+        &mut self,
+        cx: &mut Context,
+        messages: &mut VecDeque<(NetlinkMessage<T>, SocketAddr)>,
+    ) -> Result<(), ()> {
+        match self {
+            UnsollicitedMessagesSender::Unbounded(tx) => {
+                while let Some(message) = messages.pop_front() {
+                    // The channel is unbounded so the only error that can
+                    // occur is that the channel is closed because the
+                    // receiver was dropped
+                    tx.unbounded_send(message).map_err(|_| ())?;
+                }
+            }
+            UnsollicitedMessagesSender::Bounded {
+                tx,
+                capacity,
+                policy,
+                overrun,
+            } => {
+                while *overrun || !messages.is_empty() {
+                    match tx.poll_ready(cx) {
+                        Poll::Ready(Ok(())) => {}
+                        Poll::Ready(Err(_)) => return Err(()),
+                        Poll::Pending => break,
+                    }
+                    let message = if *overrun {
+                        *overrun = false;
+                        overrun_message()
+                    } else {
+                        messages.pop_front().unwrap()
+                    };
+                    tx.start_send(message).map_err(|_| ())?;
+                }
+
+                match policy {
+                    OverflowPolicy::Block => {}
+                    OverflowPolicy::DropOldest => {
+                        while messages.len() > *capacity {
+                            let (message, source) = messages.pop_front().unwrap();
+                            warn!(
+                                "channel full, dropping unsollicited message {:?} from {:?}",
+                                message, source
+                            );
+                        }
+                    }
+                    OverflowPolicy::Overrun => {
+                        if !messages.is_empty() {
+                            warn!(
+                                "channel full, dropping {} unsollicited messages",
+                                messages.len()
+                            );
+                            messages.clear();
+                            *overrun = true;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn overrun_message<T>() -> (NetlinkMessage<T>, SocketAddr)
+where
+    T: Debug + Clone + PartialEq + Eq,
+{
+    let header = NetlinkHeader {
+        message_type: NLMSG_OVERRUN,
+        ..Default::default()
+    };
+    let message = NetlinkMessage::new(header, NetlinkPayload::Overrun(vec![]));
+    (message, SocketAddr::new(0, 0))
+}
+
 /// Connection to a Netlink socket, running in the background.
 ///
 /// [`ConnectionHandle`](struct.ConnectionHandle.html) are used to pass new requests to the
@@ -34,7 +212,10 @@ where
 
     /// Channel used to transmit to the ConnectionHandle the unsollicited messages received from the
     /// socket (multicast messages for instance).
-    unsollicited_messages_tx: Option<UnboundedSender<(NetlinkMessage<T>, SocketAddr)>>,
+    unsollicited_messages_tx: Option<UnsollicitedMessagesSender<T>>,
+
+    /// Timer that fires when the earliest pending request times out.
+    timer: Option<Delay>,
 
     socket_closed: bool,
 }
@@ -45,7 +226,7 @@ where
 {
     pub(crate) fn new(
         requests_rx: UnboundedReceiver<Request<T>>,
-        unsollicited_messages_tx: UnboundedSender<(NetlinkMessage<T>, SocketAddr)>,
+        unsollicited_messages_tx: UnsollicitedMessagesSender<T>,
         protocol: NetlinkProtocol,
     ) -> io::Result<Self> {
         let socket = Socket::new(protocol)?;
@@ -54,6 +235,7 @@ where
             protocol: Protocol::new(),
             requests_rx: Some(requests_rx),
             unsollicited_messages_tx: Some(unsollicited_messages_tx),
+            timer: None,
             socket_closed: false,
         })
     }
@@ -62,6 +244,30 @@ where
         self.socket.get_mut()
     }
 
+    /// Set the maximum size of the buffer used to receive datagrams. Buffers are sized to fit
+    /// each incoming datagram, and the datagrams that exceed this limit are dropped. The default
+    /// is 1MB.
+    ///
+    /// When a dropped datagram is a response, the request it answers fails: its response stream
+    /// ends with an error message whose code is `-EMSGSIZE`. Otherwise, a message with a
+    /// `NetlinkPayload::Overrun` payload is sent on the un-sollicited messages stream.
+    pub fn set_max_buffer_size(&mut self, size: usize) {
+        self.socket.set_max_reader_capacity(size);
+    }
+
+    /// Set the default timeout for requests sent through this connection.
+    /// When a request is not fully answered within that delay, its
+    /// response stream ends with an `ETIMEDOUT` error message. Requests
+    /// sent with
+    /// [`ConnectionHandle::request_with_timeout`](struct.ConnectionHandle.html#method.request_with_timeout)
+    /// override this value. By default, requests never time out.
+    ///
+    /// Timeouts rely on the tokio timer, so the runtime driving the
+    /// connection must have it enabled.
+    pub fn set_request_timeout(&mut self, timeout: Option<Duration>) {
+        self.protocol.set_request_timeout(timeout);
+    }
+
     pub fn poll_send_messages(&mut self, cx: &mut Context) {
         trace!("poll_send_messages called");
         let Connection {
@@ -71,18 +277,33 @@ where
         } = self;
         let mut socket = Pin::new(socket);
 
-        while !protocol.outgoing_messages.is_empty() {
-            trace!("found outgoing message to send checking if socket is ready");
-            if let Poll::Ready(Err(e)) = Pin::as_mut(&mut socket).poll_ready(cx) {
-                // Sink errors are usually not recoverable. The socket
-                // probably shut down.
-                warn!("netlink socket shut down: {:?}", e);
-                self.socket_closed = true;
-                return;
+        let mut batch = Batch::default();
+
+        while let Some((message, addr, batched)) = protocol.outgoing_messages.front() {
+            let len = message.buffer_len();
+
+            // Unless the message can be appended to the current batch,
+            // the previous datagram must be sent first.
+            if !batch.can_append(len, addr, *batched) {
+                trace!("found outgoing message to send checking if socket is ready");
+                match Pin::as_mut(&mut socket).poll_ready(cx) {
+                    Poll::Ready(Ok(())) => {}
+                    Poll::Ready(Err(e)) => {
+                        // Sink errors are usually not recoverable. The socket
+                        // probably shut down.
+                        warn!("netlink socket shut down: {:?}", e);
+                        self.socket_closed = true;
+                        return;
+                    }
+                    // The previous datagram is still waiting to be sent: the
+                    // message must not be appended to it.
+                    Poll::Pending => return,
+                }
             }
 
-            let (mut message, addr) = protocol.outgoing_messages.pop_front().unwrap();
+            let (mut message, addr, batched) = protocol.outgoing_messages.pop_front().unwrap();
             message.finalize();
+            batch.push(len, addr, batched);
 
             trace!("sending outgoing message");
             if let Err(e) = Pin::as_mut(&mut socket).start_send((message, addr)) {
@@ -109,8 +330,34 @@ where
         let mut socket = Pin::new(&mut self.socket);
 
         loop {
+            if let Some(ref tx) = self.unsollicited_messages_tx {
+                if tx.is_blocked(self.protocol.incoming_requests.len()) {
+                    trace!("unsollicited messages channel full, not reading socket");
+                    return;
+                }
+            }
+
             trace!("polling socket");
-            match socket.as_mut().poll_next(cx) {
+            let result = socket.as_mut().poll_next(cx);
+            if socket.as_mut().get_mut().take_overrun() {
+                // Let the user know that some messages were lost, so that
+                // they can resynchronize their state if necessary.
+                self.protocol.incoming_requests.push_back(overrun_message());
+                // The lost messages may be responses, so the pending
+                // requests may never complete.
+                self.protocol.fail_requests(-libc::ENOBUFS);
+            }
+            for (sequence_number, source) in socket.as_mut().get_mut().take_dropped() {
+                // A dropped response fails the request it answers. If
+                // no request matches, an un-sollicited message was lost.
+                if !self
+                    .protocol
+                    .fail_request(sequence_number, source, -libc::EMSGSIZE)
+                {
+                    self.protocol.incoming_requests.push_back(overrun_message());
+                }
+            }
+            match result {
                 Poll::Ready(Some((message, addr))) => {
                     trace!("read datagram from socket");
                     self.protocol.handle_message(message, addr);
@@ -143,7 +390,7 @@ where
         }
     }
 
-    pub fn forward_unsollicited_messages(&mut self) {
+    pub fn forward_unsollicited_messages(&mut self, cx: &mut Context) {
         if self.unsollicited_messages_tx.is_none() {
             while let Some((message, source)) = self.protocol.incoming_requests.pop_front() {
                 warn!(
@@ -155,35 +402,23 @@ where
         }
 
         trace!("forward_unsollicited_messages called");
-        let mut ready = false;
-
         let Connection {
             ref mut protocol,
             ref mut unsollicited_messages_tx,
             ..
         } = self;
 
-        while let Some((message, source)) = protocol.incoming_requests.pop_front() {
-            if unsollicited_messages_tx
-                .as_mut()
-                .unwrap()
-                .unbounded_send((message, source))
-                .is_err()
-            {
-                // The channel is unbounded so the only error that can
-                // occur is that the channel is closed because the
-                // receiver was dropped
-                warn!("failed to forward message to connection handle: channel closed");
-                ready = true;
-                break;
-            }
-        }
-
-        if ready {
+        if unsollicited_messages_tx
+            .as_mut()
+            .unwrap()
+            .forward(cx, &mut protocol.incoming_requests)
+            .is_err()
+        {
+            warn!("failed to forward message to connection handle: channel closed");
             // The channel is closed so we can drop the sender.
             let _ = self.unsollicited_messages_tx.take();
             // purge `protocol.incoming_requests`
-            self.forward_unsollicited_messages();
+            self.forward_unsollicited_messages(cx);
         }
 
         trace!("forward_unsollicited_messages done");
@@ -214,13 +449,13 @@ where
                         trace!("not forwarding Ack/Done message to the handle");
                         continue;
                     }
-                    // I'm not sure how we should handle overrun messages
-                    Overrun(_) => unimplemented!("overrun is not handled yet"),
                     // We need to forward error messages and messages
                     // that are part of the netlink subprotocol,
                     // because only the user knows how they want to
-                    // handle them.
-                    Error(_) | InnerMessage(_) => {}
+                    // handle them. Overrun messages tell that the
+                    // response is incomplete, so they are forwarded
+                    // too.
+                    Error(_) | Overrun(_) | InnerMessage(_) => {}
                 }
             }
 
@@ -234,6 +469,30 @@ where
         trace!("forward_responses done");
     }
 
+    pub fn poll_timeouts(&mut self, cx: &mut Context) {
+        trace!("poll_timeouts called");
+        loop {
+            self.protocol.expire_requests(Instant::now());
+            self.forward_responses();
+
+            let deadline = match self.protocol.next_deadline() {
+                Some(deadline) => tokio::time::Instant::from_std(deadline),
+                None => {
+                    let _ = self.timer.take();
+                    break;
+                }
+            };
+            match self.timer {
+                Some(ref mut timer) => timer.reset(deadline),
+                None => self.timer = Some(delay_until(deadline)),
+            }
+            if Pin::new(self.timer.as_mut().unwrap()).poll(cx).is_pending() {
+                break;
+            }
+        }
+        trace!("poll_timeouts done");
+    }
+
     pub fn should_shut_down(&self) -> bool {
         self.socket_closed
             || (self.unsollicited_messages_tx.is_none() && self.requests_rx.is_none())
@@ -254,7 +513,7 @@ where
         pinned.poll_read_messages(cx);
 
         debug!("forwarding unsollicited messages to the connection handle");
-        pinned.forward_unsollicited_messages();
+        pinned.forward_unsollicited_messages(cx);
 
         debug!("forwaring responses to previous requests to the connection handle");
         pinned.forward_responses();
@@ -265,6 +524,9 @@ where
         debug!("sending messages");
         pinned.poll_send_messages(cx);
 
+        debug!("expiring timed out requests");
+        pinned.poll_timeouts(cx);
+
         trace!("done polling Connection");
 
         if pinned.should_shut_down() {
@@ -274,3 +536,220 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{
+        channel::mpsc::{channel, Receiver},
+        task::noop_waker,
+        StreamExt,
+    };
+    use netlink_packet_core::{NetlinkBuffer, NLM_F_ACK, NLM_F_REQUEST};
+    use netlink_packet_route::{LinkMessage, RtnlMessage, RTM_NEWLINK};
+
+    /// Pack messages described by their length, destination port and whether they are batched,
+    /// and return the number of messages in each datagram.
+    fn datagrams(messages: &[(usize, u32, bool)]) -> Vec<usize> {
+        let mut batch = Batch::default();
+        let mut datagrams: Vec<usize> = vec![];
+        for &(len, port, batched) in messages {
+            let addr = SocketAddr::new(port, 0);
+            if batch.can_append(len, &addr, batched) {
+                *datagrams.last_mut().unwrap() += 1;
+            } else {
+                datagrams.push(1);
+            }
+            batch.push(len, addr, batched);
+        }
+        datagrams
+    }
+
+    #[test]
+    fn batch_split_at_max_size() {
+        let len = MAX_BATCH_SIZE / 4;
+        assert_eq!(datagrams(&[(len, 0, true); 4]), vec![4]);
+        assert_eq!(datagrams(&[(len, 0, true); 9]), vec![4, 4, 1]);
+        assert_eq!(
+            datagrams(&[(len, 0, true), (MAX_BATCH_SIZE, 0, true), (len, 0, true)]),
+            vec![1, 1, 1]
+        );
+    }
+
+    #[test]
+    fn batch_split_on_destination_change() {
+        assert_eq!(
+            datagrams(&[
+                (100, 0, true),
+                (100, 0, true),
+                (100, 42, true),
+                (100, 42, true),
+                (100, 0, true),
+            ]),
+            vec![2, 2, 1]
+        );
+    }
+
+    #[test]
+    fn messages_not_batched_are_sent_alone() {
+        assert_eq!(datagrams(&[(100, 0, false); 3]), vec![1, 1, 1]);
+        assert_eq!(
+            datagrams(&[
+                (100, 0, true),
+                (100, 0, false),
+                (100, 0, true),
+                (100, 0, true),
+            ]),
+            vec![1, 1, 2]
+        );
+    }
+
+    type Message = (NetlinkMessage<RtnlMessage>, SocketAddr);
+
+    fn messages(sequence_numbers: std::ops::RangeInclusive<u32>) -> VecDeque<Message> {
+        sequence_numbers
+            .map(|sequence_number| {
+                let mut message =
+                    NetlinkMessage::from(RtnlMessage::NewLink(LinkMessage::default()));
+                message.header.sequence_number = sequence_number;
+                (message, SocketAddr::new(0, 0))
+            })
+            .collect()
+    }
+
+    /// Receive the messages waiting in the channel, and return their sequence number, or `None`
+    /// for overrun messages.
+    fn received(cx: &mut Context, rx: &mut Receiver<Message>) -> Vec<Option<u32>> {
+        let mut received = vec![];
+        while let Poll::Ready(Some((message, _))) = rx.poll_next_unpin(cx) {
+            received.push(match message.payload {
+                NetlinkPayload::Overrun(_) => None,
+                _ => Some(message.header.sequence_number),
+            });
+        }
+        received
+    }
+
+    // A channel created with a capacity of 2 holds 3 messages: one more per sender.
+
+    #[test]
+    fn forward_block() {
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        let (tx, mut rx) = channel(2);
+        let mut sender = UnsollicitedMessagesSender::bounded(tx, 2, OverflowPolicy::Block);
+
+        let mut backlog = messages(1..=5);
+        sender.forward(&mut cx, &mut backlog).unwrap();
+        // the messages that do not fit are kept, and the socket is not read anymore
+        assert_eq!(backlog.len(), 2);
+        assert!(sender.is_blocked(backlog.len()));
+        assert_eq!(received(&mut cx, &mut rx), vec![Some(1), Some(2), Some(3)]);
+
+        sender.forward(&mut cx, &mut backlog).unwrap();
+        assert!(backlog.is_empty());
+        assert!(!sender.is_blocked(backlog.len()));
+        assert_eq!(received(&mut cx, &mut rx), vec![Some(4), Some(5)]);
+    }
+
+    #[test]
+    fn forward_drop_oldest() {
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        let (tx, mut rx) = channel(2);
+        let mut sender = UnsollicitedMessagesSender::bounded(tx, 2, OverflowPolicy::DropOldest);
+
+        let mut backlog = messages(1..=7);
+        sender.forward(&mut cx, &mut backlog).unwrap();
+        // only the `capacity` most recent messages are kept
+        assert_eq!(backlog.len(), 2);
+        assert!(!sender.is_blocked(backlog.len()));
+        assert_eq!(received(&mut cx, &mut rx), vec![Some(1), Some(2), Some(3)]);
+
+        sender.forward(&mut cx, &mut backlog).unwrap();
+        assert!(backlog.is_empty());
+        assert_eq!(received(&mut cx, &mut rx), vec![Some(6), Some(7)]);
+    }
+
+    #[test]
+    fn forward_overrun() {
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        let (tx, mut rx) = channel(2);
+        let mut sender = UnsollicitedMessagesSender::bounded(tx, 2, OverflowPolicy::Overrun);
+
+        let mut backlog = messages(1..=5);
+        sender.forward(&mut cx, &mut backlog).unwrap();
+        // the messages that do not fit are dropped
+        assert!(backlog.is_empty());
+        assert!(!sender.is_blocked(backlog.len()));
+        assert_eq!(received(&mut cx, &mut rx), vec![Some(1), Some(2), Some(3)]);
+
+        // once there is room, an overrun message is sent first
+        let mut backlog = messages(6..=6);
+        sender.forward(&mut cx, &mut backlog).unwrap();
+        assert_eq!(received(&mut cx, &mut rx), vec![None, Some(6)]);
+
+        // then the messages are forwarded as usual
+        let mut backlog = messages(7..=8);
+        sender.forward(&mut cx, &mut backlog).unwrap();
+        assert_eq!(received(&mut cx, &mut rx), vec![Some(7), Some(8)]);
+    }
+
+    #[test]
+    fn forward_closed_channel() {
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        let (tx, rx) = channel(2);
+        let mut sender = UnsollicitedMessagesSender::bounded(tx, 2, OverflowPolicy::Block);
+        drop(rx);
+        assert!(sender.forward(&mut cx, &mut messages(1..=1)).is_err());
+    }
+
+    /// Wait for a request on `peer`, then send two datagrams of `len` bytes to the sender: a
+    /// response to the request, and an unsollicited message.
+    async fn send_oversized_messages(mut peer: Socket, len: usize) {
+        let mut buf = vec![0; 4096];
+        let (_, addr) = peer.recv_from(&mut buf).await.unwrap();
+        let sequence_number = NetlinkBuffer::new(&buf).sequence_number();
+
+        for &sequence_number in &[sequence_number, sequence_number + 1000] {
+            let mut datagram = vec![0; len];
+            let mut buffer = NetlinkBuffer::new(&mut datagram);
+            buffer.set_length(len as u32);
+            buffer.set_message_type(RTM_NEWLINK);
+            buffer.set_sequence_number(sequence_number);
+            peer.send_to(&datagram, &addr).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn oversized_datagrams() {
+        // Unlike NETLINK_ROUTE, NETLINK_USERSOCK lets unprivileged sockets send messages to
+        // each other.
+        let mut peer = Socket::new(NetlinkProtocol::UserSock).unwrap();
+        let peer_addr = peer.bind_auto().unwrap();
+        tokio::spawn(send_oversized_messages(peer, 2000));
+
+        let (mut connection, mut handle, mut messages) =
+            crate::new_connection::<RtnlMessage>(NetlinkProtocol::UserSock).unwrap();
+        connection.socket_mut().bind_auto().unwrap();
+        connection.set_max_buffer_size(1000);
+        tokio::spawn(connection);
+
+        let mut request = NetlinkMessage::from(RtnlMessage::GetLink(LinkMessage::default()));
+        request.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+        let responses: Vec<_> = handle.request(request, peer_addr).unwrap().collect().await;
+
+        // the response is too big: the request fails
+        assert_eq!(responses.len(), 1);
+        match responses[0].payload {
+            NetlinkPayload::Error(ref e) => assert_eq!(e.code, -libc::EMSGSIZE),
+            ref payload => panic!("expected an error message, got {:?}", payload),
+        }
+
+        // the unsollicited message is too big too: it is reported as an overrun
+        let (message, _) = messages.next().await.unwrap();
+        assert!(matches!(message.payload, NetlinkPayload::Overrun(_)));
+    }
+}
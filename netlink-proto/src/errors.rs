@@ -2,7 +2,7 @@ use std::error::Error as StdError;
 use std::fmt::{self, Debug};
 use std::io;
 
-use netlink_packet_core::NetlinkMessage;
+use netlink_packet_core::{ErrorMessage, NetlinkMessage, NetlinkPayload};
 
 #[derive(Debug)]
 pub struct Error<T>
@@ -23,6 +23,34 @@ where
     pub fn into_inner(self) -> ErrorKind<T> {
         self.kind
     }
+
+    /// Return the error message sent by the kernel, if this error is an error response.
+    pub fn error_message(&self) -> Option<&ErrorMessage> {
+        match self.kind() {
+            ErrorKind::KernelError(ref message) => Some(message),
+            ErrorKind::NetlinkError(NetlinkMessage {
+                payload: NetlinkPayload::Error(ref message),
+                ..
+            }) => Some(message),
+            _ => None,
+        }
+    }
+
+    /// Return the (positive) errno value associated with this error, if any.
+    pub fn raw_os_error(&self) -> Option<i32> {
+        match self.kind() {
+            ErrorKind::SocketIo(ref e) => e.raw_os_error(),
+            _ => self.error_message().map(|message| message.code.abs()),
+        }
+    }
+
+    /// Return the `std::io::ErrorKind` corresponding to this error's errno, if any.
+    pub fn io_error_kind(&self) -> Option<io::ErrorKind> {
+        match self.kind() {
+            ErrorKind::SocketIo(ref e) => Some(e.kind()),
+            _ => self.error_message().map(|message| message.to_io().kind()),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -38,6 +66,13 @@ where
 
     /// Error while reading from or writing to the netlink socket
     SocketIo(io::Error),
+
+    /// The kernel answered a request with an error. Unlike `NetlinkError`, only the error
+    /// message itself is kept.
+    KernelError(ErrorMessage),
+
+    /// The kernel tables changed while they were being dumped, so the dump may be inconsistent
+    DumpInterrupted,
 }
 
 impl<T> From<ErrorKind<T>> for Error<T>
@@ -59,6 +94,8 @@ where
             SocketIo(ref e) => write!(f, "{}: {}", self.description(), e),
             ConnectionClosed => write!(f, "{}", self.description()),
             NetlinkError(ref message) => write!(f, "{}: {:?}", self.description(), message),
+            KernelError(ref message) => write!(f, "The kernel answered with an error: {}", message),
+            DumpInterrupted => write!(f, "The dump was interrupted and may be inconsistent"),
         }
     }
 }
@@ -73,6 +110,8 @@ where
             SocketIo(_) => "Error while reading from or writing to the netlink socket",
             ConnectionClosed => "The netlink connection is closed",
             NetlinkError(_) => "Received an error message as a response",
+            KernelError(_) => "The kernel answered with an error",
+            DumpInterrupted => "The dump was interrupted and may be inconsistent",
         }
     }
 
@@ -6,7 +6,8 @@ use std::{
 };
 
 use futures::{Sink, Stream};
-use log::error;
+use log::{error, warn};
+use netlink_packet_core::{NetlinkBuffer, NETLINK_HEADER_LEN};
 use netlink_sys::{Socket, SocketAddr};
 use tokio_util::codec::{Decoder, Encoder};
 
@@ -18,6 +19,9 @@ pub struct NetlinkFramed<C> {
     in_addr: SocketAddr,
     out_addr: SocketAddr,
     flushed: bool,
+    overrun: bool,
+    max_reader_capacity: usize,
+    dropped: Vec<(u32, SocketAddr)>,
 }
 
 impl<C> Stream for NetlinkFramed<C>
@@ -33,6 +37,9 @@ where
             ref mut socket,
             ref mut in_addr,
             ref mut reader,
+            ref mut overrun,
+            ref max_reader_capacity,
+            ref mut dropped,
             ..
         } = Pin::get_mut(self);
 
@@ -46,31 +53,93 @@ where
                 }
             }
 
-            reader.clear();
-            reader.reserve(INITIAL_READER_CAPACITY);
-
-            *in_addr = unsafe {
-                // Read into the buffer without having to initialize the memory.
-                //
-                // safety: we know poll_recv_from never reads from the
-                // memory during a recv so it's fine to turn &mut
-                // [<MaybeUninitialized<u8>>] into &mut[u8]
-                let bytes = &mut *(reader.bytes_mut() as *mut _ as *mut [u8]);
-                match ready!(socket.poll_recv_from(cx, bytes)) {
-                    Ok((n, addr)) => {
-                        reader.advance_mut(n);
-                        addr
-                    }
+            // Peek at the next datagram to find out how big it is, so that
+            // large messages (links with many VFs for instance) are not
+            // truncated.
+            let len = match ready!(socket.poll_recv_from_with_flags(
+                cx,
+                &mut [],
+                libc::MSG_PEEK | libc::MSG_TRUNC
+            )) {
+                Ok((len, _)) => len,
+                Err(e) => match handle_recv_error(e, overrun) {
+                    Ok(()) => continue,
+                    Err(()) => return Poll::Ready(None),
+                },
+            };
+            if len > *max_reader_capacity {
+                warn!(
+                    "dropping datagram of {} bytes: it exceeds the maximum buffer size ({} bytes)",
+                    len, max_reader_capacity
+                );
+                // Only read the header of the first message, to find out
+                // which request the datagram answers.
+                let mut header = [0; NETLINK_HEADER_LEN];
+                match ready!(socket.poll_recv_from_with_flags(cx, &mut header, 0)) {
+                    Ok((_, addr)) => dropped.push((sequence_number(&header), addr)),
                     Err(e) => {
-                        error!("failed to read from netlink socket: {:?}", e);
+                        if handle_recv_error(e, overrun).is_err() {
+                            return Poll::Ready(None);
+                        }
+                    }
+                }
+                continue;
+            }
+
+            reader.clear();
+            reader.reserve(len);
+
+            // Read into the buffer without having to initialize the memory.
+            //
+            // safety: we know poll_recv_from never reads from the
+            // memory during a recv so it's fine to turn &mut
+            // [<MaybeUninitialized<u8>>] into &mut[u8]
+            let bytes = unsafe { &mut *(reader.bytes_mut() as *mut _ as *mut [u8]) };
+            let capacity = bytes.len();
+            // With MSG_TRUNC, the actual length of the datagram is returned,
+            // even if it did not fit in the buffer.
+            match ready!(socket.poll_recv_from_with_flags(cx, bytes, libc::MSG_TRUNC)) {
+                Ok((n, addr)) if n > capacity => {
+                    error!(
+                        "dropping truncated datagram: {} bytes received but only {} could be read",
+                        n, capacity
+                    );
+                    dropped.push((sequence_number(bytes), addr));
+                }
+                Ok((n, addr)) => {
+                    unsafe { reader.advance_mut(n) };
+                    *in_addr = addr;
+                }
+                Err(e) => {
+                    if handle_recv_error(e, overrun).is_err() {
                         return Poll::Ready(None);
                     }
                 }
-            };
+            }
         }
     }
 }
 
+/// Return the sequence number of the first message in a datagram
+fn sequence_number(datagram: &[u8]) -> u32 {
+    NetlinkBuffer::new(datagram).sequence_number()
+}
+
+/// Decide whether an error returned when reading from the socket is
+/// recoverable.
+fn handle_recv_error(e: io::Error, overrun: &mut bool) -> Result<(), ()> {
+    // The socket receive buffer overflowed and the kernel dropped
+    // messages. The socket is still usable though.
+    if e.raw_os_error() == Some(libc::ENOBUFS) {
+        warn!("netlink socket receive buffer overflowed, messages were lost");
+        *overrun = true;
+        Ok(())
+    } else {
+        error!("failed to read from netlink socket: {:?}", e);
+        Err(())
+    }
+}
+
 impl<C: Encoder + Unpin> Sink<(C::Item, SocketAddr)> for NetlinkFramed<C> {
     type Error = C::Error;
 
@@ -139,6 +208,10 @@ impl<C: Encoder + Unpin> Sink<(C::Item, SocketAddr)> for NetlinkFramed<C> {
 // message since Linux 4.9 (16KB before). See:
 // https://git.kernel.org/pub/scm/linux/kernel/git/davem/net-next.git/commit/?id=d35c99ff77ecb2eb239731b799386f3b3637a31e
 const INITIAL_READER_CAPACITY: usize = 64 * 1024;
+// Datagrams can still be larger than that, when a single message does
+// not fit (a link with many VF for instance), so the buffer grows as
+// needed, up to this limit.
+const DEFAULT_MAX_READER_CAPACITY: usize = 1024 * 1024;
 const INITIAL_WRITER_CAPACITY: usize = 8 * 1024;
 
 impl<C> NetlinkFramed<C> {
@@ -154,9 +227,33 @@ impl<C> NetlinkFramed<C> {
             reader: BytesMut::with_capacity(INITIAL_READER_CAPACITY),
             writer: BytesMut::with_capacity(INITIAL_WRITER_CAPACITY),
             flushed: true,
+            overrun: false,
+            max_reader_capacity: DEFAULT_MAX_READER_CAPACITY,
+            dropped: vec![],
         }
     }
 
+    /// Set the maximum size of the buffer used to read datagrams from the socket. Datagrams
+    /// larger than that are dropped, see [`take_dropped`](#method.take_dropped). The default is
+    /// 1MB.
+    pub fn set_max_reader_capacity(&mut self, capacity: usize) {
+        self.max_reader_capacity = capacity;
+    }
+
+    /// Return the datagrams that were dropped because they did not fit in the reader buffer
+    /// since the last call to this method. Each datagram is identified by the sequence number of
+    /// its first message, and the address it was received from.
+    pub fn take_dropped(&mut self) -> Vec<(u32, SocketAddr)> {
+        std::mem::take(&mut self.dropped)
+    }
+
+    /// Return whether the socket receive buffer overflowed (ie whether reading from the socket
+    /// failed with `ENOBUFS`) since the last call to this method. When this happens, some
+    /// messages sent by the kernel were lost.
+    pub fn take_overrun(&mut self) -> bool {
+        std::mem::replace(&mut self.overrun, false)
+    }
+
     /// Returns a reference to the underlying I/O stream wrapped by `Framed`.
     ///
     /// # Note
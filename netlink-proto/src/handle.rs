@@ -1,7 +1,7 @@
 use futures::channel::mpsc::{unbounded, UnboundedSender};
-use futures::Stream;
-use netlink_packet_core::NetlinkMessage;
-use std::fmt::Debug;
+use futures::{Stream, StreamExt, TryStreamExt};
+use netlink_packet_core::{NetlinkMessage, NetlinkPayload, NLM_F_DUMP_INTR};
+use std::{fmt::Debug, time::Duration};
 
 use crate::errors::{Error, ErrorKind};
 use crate::Request;
@@ -52,6 +52,103 @@ where
         Ok(rx)
     }
 
+    /// Same as [`request`](#method.request), but error messages sent by the kernel are turned
+    /// into `Err` items, with an [`ErrorKind::KernelError`](enum.ErrorKind.html) that gives
+    /// access to the errno value. This makes the stream usable with the `TryStream` combinators.
+    ///
+    /// If the kernel flags a dump as interrupted (`NLM_F_DUMP_INTR`), the stream ends with an
+    /// `ErrorKind::DumpInterrupted` error.
+    pub fn try_request(
+        &mut self,
+        message: NetlinkMessage<T>,
+        destination: SocketAddr,
+    ) -> Result<impl Stream<Item = Result<NetlinkMessage<T>, Error<T>>>, Error<T>> {
+        Ok(self
+            .request(message, destination)?
+            .map(|message| match message.payload {
+                NetlinkPayload::Error(_) if message.header.flags & NLM_F_DUMP_INTR != 0 => {
+                    Err(Error::from(ErrorKind::DumpInterrupted))
+                }
+                NetlinkPayload::Error(ref err) => {
+                    Err(Error::from(ErrorKind::KernelError(err.clone())))
+                }
+                _ => Ok(message),
+            }))
+    }
+
+    /// Send a dump request and collect the whole response. If the dump is interrupted because
+    /// the kernel tables changed in the meantime, the request is sent again, up to `max_retries`
+    /// times, after which an `ErrorKind::DumpInterrupted` error is returned.
+    pub async fn dump_with_retries(
+        &mut self,
+        message: NetlinkMessage<T>,
+        destination: SocketAddr,
+        max_retries: usize,
+    ) -> Result<Vec<NetlinkMessage<T>>, Error<T>> {
+        let mut retries = 0;
+        loop {
+            let result = self
+                .try_request(message.clone(), destination)?
+                .try_collect()
+                .await;
+            match result {
+                Err(ref e) if retries < max_retries => {
+                    if let ErrorKind::DumpInterrupted = e.kind() {
+                        retries += 1;
+                        debug!("dump interrupted, retrying ({}/{})", retries, max_retries);
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+            return result;
+        }
+    }
+
+    /// Same as [`request`](#method.request), but if the request is not fully answered within
+    /// `timeout`, the stream ends with an error message whose code is `-ETIMEDOUT`. This
+    /// overrides the connection's default timeout.
+    pub fn request_with_timeout(
+        &mut self,
+        message: NetlinkMessage<T>,
+        destination: SocketAddr,
+        timeout: Duration,
+    ) -> Result<impl Stream<Item = NetlinkMessage<T>>, Error<T>> {
+        let (tx, rx) = unbounded::<NetlinkMessage<T>>();
+        let mut request = Request::from((message, destination, tx));
+        request.timeout = Some(timeout);
+        debug!("handle: forwarding new request to connection");
+        UnboundedSender::unbounded_send(&self.requests_tx, request)
+            .map_err(|_| Error::from(ErrorKind::ConnectionClosed))?;
+        Ok(rx)
+    }
+
+    /// Send several requests at once. The messages are packed in as few datagrams as possible,
+    /// which is much faster than sending them one by one when there are many of them. Each
+    /// message still gets its own sequence number, and its own response stream: the streams
+    /// are returned in the same order as the messages.
+    ///
+    /// Note that a message only gets a response if it has one of the `NLM_F_REQUEST`,
+    /// `NLM_F_ACK` or `NLM_F_ECHO` flags, so to know whether each message succeeded, the
+    /// `NLM_F_ACK` flag should be set.
+    pub fn request_batch(
+        &mut self,
+        messages: Vec<NetlinkMessage<T>>,
+        destination: SocketAddr,
+    ) -> Result<Vec<impl Stream<Item = NetlinkMessage<T>>>, Error<T>> {
+        let mut responses = Vec::with_capacity(messages.len());
+        for message in messages {
+            let (tx, rx) = unbounded::<NetlinkMessage<T>>();
+            let mut request = Request::from((message, destination, tx));
+            request.batched = true;
+            UnboundedSender::unbounded_send(&self.requests_tx, request)
+                .map_err(|_| Error::from(ErrorKind::ConnectionClosed))?;
+            responses.push(rx);
+        }
+        debug!("handle: forwarded batch of {} requests", responses.len());
+        Ok(responses)
+    }
+
     pub fn notify(
         &mut self,
         message: NetlinkMessage<T>,
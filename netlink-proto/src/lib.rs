@@ -169,6 +169,7 @@ pub(crate) type Request<T> =
     self::protocol::Request<T, UnboundedSender<crate::packet::NetlinkMessage<T>>>;
 
 mod connection;
+use crate::connection::UnsollicitedMessagesSender;
 pub use crate::connection::*;
 
 mod errors;
@@ -177,7 +178,7 @@ pub use crate::errors::*;
 mod handle;
 pub use crate::handle::*;
 
-use futures::channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use futures::channel::mpsc::{channel, unbounded, Receiver, UnboundedReceiver, UnboundedSender};
 use std::fmt::Debug;
 use std::io;
 
@@ -200,6 +201,13 @@ pub use netlink_sys as sys;
 /// about the `NetlinkSerializable` and `NetlinkDeserializable`
 /// traits.
 ///
+/// If the socket receive buffer overflows, some messages are lost and
+/// a message with a `NetlinkPayload::Overrun` payload is sent on the
+/// un-sollicited messages stream, but the connection keeps running.
+/// Since responses may have been lost too, all the pending requests
+/// fail: their response stream ends with an error message whose code
+/// is `-ENOBUFS`.
+///
 /// Most of the time, users will want to spawn the `Connection` on an
 /// async runtime, and use the handle to send messages.
 #[allow(clippy::type_complexity)]
@@ -222,7 +230,56 @@ where
     let (requests_tx, requests_rx) = unbounded::<Request<T>>();
     let (messages_tx, messages_rx) = unbounded::<(packet::NetlinkMessage<T>, sys::SocketAddr)>();
     Ok((
-        Connection::new(requests_rx, messages_tx, protocol)?,
+        Connection::new(
+            requests_rx,
+            UnsollicitedMessagesSender::Unbounded(messages_tx),
+            protocol,
+        )?,
+        ConnectionHandle::new(requests_tx),
+        messages_rx,
+    ))
+}
+
+/// Same as [`new_connection`](fn.new_connection.html), but un-sollicited messages are forwarded
+/// through a channel that holds at most `capacity` messages. When the consumer does not keep up
+/// and the channel is full, `policy` decides what happens to the incoming messages (see
+/// [`OverflowPolicy`](enum.OverflowPolicy.html)).
+///
+/// Only the un-sollicited messages channel is bounded. Requests are still passed to the
+/// connection through an unbounded channel, because
+/// [`ConnectionHandle::request`](struct.ConnectionHandle.html#method.request) is synchronous and
+/// has no way to wait for room in the channel. Responses are also still forwarded through
+/// unbounded channels. In both cases, the amount of buffered messages is driven by the requests
+/// the user makes, not by the kernel, so callers that need to limit it should limit the number of
+/// requests they have in flight.
+#[allow(clippy::type_complexity)]
+pub fn new_bounded_connection<T>(
+    protocol: netlink_sys::Protocol,
+    capacity: usize,
+    policy: OverflowPolicy,
+) -> io::Result<(
+    Connection<T>,
+    ConnectionHandle<T>,
+    Receiver<(packet::NetlinkMessage<T>, sys::SocketAddr)>,
+)>
+where
+    T: Debug
+        + PartialEq
+        + Eq
+        + Clone
+        + packet::NetlinkSerializable<T>
+        + packet::NetlinkDeserializable<T>
+        + Unpin,
+{
+    let (requests_tx, requests_rx) = unbounded::<Request<T>>();
+    let (messages_tx, messages_rx) =
+        channel::<(packet::NetlinkMessage<T>, sys::SocketAddr)>(capacity);
+    Ok((
+        Connection::new(
+            requests_rx,
+            UnsollicitedMessagesSender::bounded(messages_tx, capacity, policy),
+            protocol,
+        )?,
         ConnectionHandle::new(requests_tx),
         messages_rx,
     ))
@@ -1,16 +1,24 @@
 use std::{
-    collections::{hash_map, HashMap, VecDeque},
+    collections::{hash_map, BTreeSet, HashMap, VecDeque},
     fmt::Debug,
+    time::{Duration, Instant},
 };
 
 use netlink_packet_core::{
-    constants::*, NetlinkDeserializable, NetlinkMessage, NetlinkPayload, NetlinkSerializable,
+    constants::*, ErrorMessage, NetlinkDeserializable, NetlinkHeader, NetlinkMessage,
+    NetlinkPayload, NetlinkSerializable, NLMSG_ERROR,
 };
 use netlink_sys::SocketAddr;
 
 use super::Request;
 
-#[derive(Debug, Eq, PartialEq, Hash)]
+/// How long the responses to a request that was aborted (because it
+/// timed out for instance) are discarded. Past that delay, late responses
+/// are treated like any other message that does not belong to a pending
+/// request.
+const LATE_RESPONSES_DELAY: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
 struct RequestId {
     sequence_number: u32,
     port: u32,
@@ -40,6 +48,9 @@ where
 struct PendingRequest<M> {
     expecting_ack: bool,
     metadata: M,
+    deadline: Option<Instant>,
+    /// Whether a response had the `NLM_F_DUMP_INTR` flag set
+    interrupted: bool,
 }
 
 #[derive(Debug, Default)]
@@ -51,18 +62,29 @@ where
     /// Counter that is incremented for each message sent
     sequence_id: u32,
 
+    /// Timeout applied to requests that do not specify one
+    request_timeout: Option<Duration>,
+
     /// Requests for which we're awaiting a response. Metadata are
     /// associated with each request.
     pending_requests: HashMap<RequestId, PendingRequest<M>>,
 
+    /// Deadlines of the pending requests, in chronological order
+    deadlines: BTreeSet<(Instant, RequestId)>,
+
+    /// Requests that were aborted, and the instant until which the
+    /// responses that may still arrive for them are discarded
+    aborted_requests: HashMap<RequestId, Instant>,
+
     /// Responses to pending requests
     pub incoming_responses: VecDeque<Response<T, M>>,
 
     /// Requests from remote peers
     pub incoming_requests: VecDeque<(NetlinkMessage<T>, SocketAddr)>,
 
-    /// The messages to be sent out
-    pub outgoing_messages: VecDeque<(NetlinkMessage<T>, SocketAddr)>,
+    /// The messages to be sent out. The boolean tells whether the
+    /// message is part of a batch.
+    pub outgoing_messages: VecDeque<(NetlinkMessage<T>, SocketAddr, bool)>,
 }
 
 impl<T, M> Protocol<T, M>
@@ -73,18 +95,37 @@ where
     pub fn new() -> Self {
         Self {
             sequence_id: 0,
+            request_timeout: None,
             pending_requests: HashMap::new(),
+            deadlines: BTreeSet::new(),
+            aborted_requests: HashMap::new(),
             incoming_responses: VecDeque::new(),
             incoming_requests: VecDeque::new(),
             outgoing_messages: VecDeque::new(),
         }
     }
 
+    pub fn set_request_timeout(&mut self, timeout: Option<Duration>) {
+        self.request_timeout = timeout;
+    }
+
     pub fn handle_message(&mut self, message: NetlinkMessage<T>, source: SocketAddr) {
         let request_id = RequestId::new(message.header.sequence_number, source.port_number());
         debug!("handling messages (request id = {:?})", request_id);
         if let hash_map::Entry::Occupied(entry) = self.pending_requests.entry(request_id) {
-            Self::handle_response(&mut self.incoming_responses, entry, message);
+            Self::handle_response(
+                &mut self.incoming_responses,
+                &mut self.deadlines,
+                entry,
+                message,
+            );
+        } else if self.aborted_requests.contains_key(&request_id) {
+            debug!("discarding late response to request {:?}", request_id);
+            // Anything but a data message ends the request, so no more
+            // responses are expected.
+            if !matches!(message.payload, NetlinkPayload::InnerMessage(_)) {
+                self.aborted_requests.remove(&request_id);
+            }
         } else {
             self.incoming_requests.push_back((message, source));
         }
@@ -92,9 +133,26 @@ where
 
     fn handle_response(
         incoming_responses: &mut VecDeque<Response<T, M>>,
-        entry: hash_map::OccupiedEntry<RequestId, PendingRequest<M>>,
-        message: NetlinkMessage<T>,
+        deadlines: &mut BTreeSet<(Instant, RequestId)>,
+        mut entry: hash_map::OccupiedEntry<RequestId, PendingRequest<M>>,
+        mut message: NetlinkMessage<T>,
     ) {
+        // The kernel sets NLM_F_DUMP_INTR when its tables changed during
+        // a dump. The results may be inconsistent, so instead of the
+        // "end of dump" message, we send an EINTR error that has this
+        // flag set.
+        if message.header.flags & NLM_F_DUMP_INTR == NLM_F_DUMP_INTR {
+            entry.get_mut().interrupted = true;
+        }
+        if entry.get().interrupted {
+            if let NetlinkPayload::Done = message.payload {
+                debug!("dump {:?} was interrupted", entry.key());
+                let mut header = message.header;
+                header.flags |= NLM_F_DUMP_INTR;
+                message = error_message(header, -libc::EINTR);
+            }
+        }
+
         let entry_key;
         let mut request_id = entry.key();
         debug!("handling response to request {:?}", request_id);
@@ -115,6 +173,9 @@ where
         let metadata = if done {
             trace!("request {:?} fully processed", request_id);
             let (k, v) = entry.remove_entry();
+            if let Some(deadline) = v.deadline {
+                deadlines.remove(&(deadline, k));
+            }
             entry_key = k;
             request_id = &entry_key;
             v.metadata
@@ -137,12 +198,15 @@ where
             mut message,
             metadata,
             destination,
+            timeout,
+            batched,
         } = request;
 
         self.set_sequence_id(&mut message);
         let request_id = RequestId::new(self.sequence_id, destination.port_number());
         let flags = message.header.flags;
-        self.outgoing_messages.push_back((message, destination));
+        self.outgoing_messages
+            .push_back((message, destination, batched));
 
         // If we expect a response, we store the request id so that we
         // can map the response to this specific request.
@@ -156,18 +220,317 @@ where
             || flags & NLM_F_ECHO == NLM_F_ECHO
             || expecting_ack
         {
+            let deadline = timeout
+                .or(self.request_timeout)
+                .map(|timeout| Instant::now() + timeout);
+            if let Some(deadline) = deadline {
+                self.deadlines.insert((deadline, request_id));
+            }
             self.pending_requests.insert(
                 request_id,
                 PendingRequest {
                     expecting_ack,
                     metadata,
+                    deadline,
+                    interrupted: false,
                 },
             );
         }
     }
 
+    /// Return the earliest deadline among the pending requests, if any.
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.deadlines.iter().next().map(|(deadline, _)| *deadline)
+    }
+
+    /// Drop the pending requests whose deadline has passed. For each of
+    /// them, an `ETIMEDOUT` error message is queued as the final
+    /// response, so that whoever is waiting for it is notified. The
+    /// responses that arrive later for these requests are discarded.
+    pub fn expire_requests(&mut self, now: Instant) {
+        self.aborted_requests.retain(|_, until| *until > now);
+
+        while let Some(&(deadline, request_id)) = self.deadlines.iter().next() {
+            if deadline > now {
+                break;
+            }
+            debug!("request {:?} timed out", request_id);
+            self.abort_request(request_id, -libc::ETIMEDOUT, now);
+        }
+    }
+
+    /// Abort all the pending requests, with an error message carrying
+    /// `code` as their final response. This is used when responses may
+    /// have been lost, for instance because the socket receive buffer
+    /// overflowed.
+    pub fn fail_requests(&mut self, code: i32) {
+        let now = Instant::now();
+        let request_ids: Vec<RequestId> = self.pending_requests.keys().copied().collect();
+        for request_id in request_ids {
+            debug!("request {:?} failed with code {}", request_id, code);
+            self.abort_request(request_id, code, now);
+        }
+    }
+
+    /// Abort the pending request that a message received from `source`
+    /// with the given sequence number would answer, with an error message
+    /// carrying `code` as its final response. Return `false` if there is
+    /// no such request.
+    pub fn fail_request(&mut self, sequence_number: u32, source: SocketAddr, code: i32) -> bool {
+        let request_id = RequestId::new(sequence_number, source.port_number());
+        let failed = self.abort_request(request_id, code, Instant::now());
+        if failed {
+            debug!("request {:?} failed with code {}", request_id, code);
+        }
+        failed
+    }
+
+    /// Drop a pending request, and queue an error message carrying `code`
+    /// as its final response. The responses that arrive later for this
+    /// request are discarded. Return `false` if the request is not
+    /// pending.
+    fn abort_request(&mut self, request_id: RequestId, code: i32, now: Instant) -> bool {
+        let request = match self.pending_requests.remove(&request_id) {
+            Some(request) => request,
+            None => return false,
+        };
+        if let Some(deadline) = request.deadline {
+            self.deadlines.remove(&(deadline, request_id));
+        }
+        self.aborted_requests
+            .insert(request_id, now + LATE_RESPONSES_DELAY);
+        let header = NetlinkHeader {
+            sequence_number: request_id.sequence_number,
+            port_number: request_id.port,
+            ..Default::default()
+        };
+        self.incoming_responses.push_back(Response {
+            done: true,
+            message: error_message(header, code),
+            metadata: request.metadata,
+        });
+        true
+    }
+
     fn set_sequence_id(&mut self, message: &mut NetlinkMessage<T>) {
         self.sequence_id += 1;
         message.header.sequence_number = self.sequence_id;
     }
 }
+
+/// Build an error message with the given code, as if it were sent by the
+/// kernel.
+fn error_message<T>(mut header: NetlinkHeader, code: i32) -> NetlinkMessage<T>
+where
+    T: Debug + Clone + PartialEq + Eq + NetlinkSerializable<T> + NetlinkDeserializable<T>,
+{
+    header.message_type = NLMSG_ERROR;
+    let payload = NetlinkPayload::Error(ErrorMessage::new(code, vec![]));
+    NetlinkMessage::new(header, payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use netlink_packet_route::{LinkMessage, RtnlMessage};
+
+    fn kernel() -> SocketAddr {
+        SocketAddr::new(0, 0)
+    }
+
+    /// Queue a request with the given flags and metadata, and return its sequence number
+    fn request(
+        protocol: &mut Protocol<RtnlMessage, u32>,
+        flags: u16,
+        timeout: Option<Duration>,
+        metadata: u32,
+    ) -> u32 {
+        let mut message = NetlinkMessage::from(RtnlMessage::GetLink(LinkMessage::default()));
+        message.header.flags = flags;
+        protocol.request(Request {
+            metadata,
+            message,
+            destination: kernel(),
+            timeout,
+            batched: false,
+        });
+        let (message, _, _) = protocol.outgoing_messages.pop_back().unwrap();
+        message.header.sequence_number
+    }
+
+    fn response(
+        sequence_number: u32,
+        flags: u16,
+        payload: NetlinkPayload<RtnlMessage>,
+    ) -> NetlinkMessage<RtnlMessage> {
+        let header = NetlinkHeader {
+            sequence_number,
+            flags,
+            ..Default::default()
+        };
+        NetlinkMessage::new(header, payload)
+    }
+
+    fn link(sequence_number: u32) -> NetlinkMessage<RtnlMessage> {
+        let payload = NetlinkPayload::InnerMessage(RtnlMessage::NewLink(LinkMessage::default()));
+        response(sequence_number, NLM_F_MULTIPART, payload)
+    }
+
+    fn error_code(response: &Response<RtnlMessage, u32>) -> i32 {
+        match response.message.payload {
+            NetlinkPayload::Error(ref e) => e.code,
+            ref payload => panic!("expected an error message, got {:?}", payload),
+        }
+    }
+
+    #[test]
+    fn fail_requests() {
+        let mut protocol = Protocol::new();
+        let dump = request(&mut protocol, NLM_F_REQUEST | NLM_F_DUMP, None, 1);
+        let ack = request(&mut protocol, NLM_F_REQUEST | NLM_F_ACK, None, 2);
+        protocol.handle_message(link(dump), kernel());
+        assert_eq!(protocol.incoming_responses.pop_front().unwrap().metadata, 1);
+
+        protocol.fail_requests(-libc::ENOBUFS);
+        let mut responses: Vec<_> = protocol.incoming_responses.drain(..).collect();
+        responses.sort_by_key(|response| response.metadata);
+        assert_eq!(responses.len(), 2);
+        for (response, sequence_number) in responses.iter().zip(&[dump, ack]) {
+            assert!(response.done);
+            assert_eq!(response.message.header.sequence_number, *sequence_number);
+            assert_eq!(error_code(response), -libc::ENOBUFS);
+        }
+        assert!(protocol.pending_requests.is_empty());
+
+        // the responses that were still on their way are discarded
+        protocol.handle_message(link(dump), kernel());
+        protocol.handle_message(response(dump, 0, NetlinkPayload::Done), kernel());
+        let ack_message = ErrorMessage::new(0, vec![]);
+        protocol.handle_message(response(ack, 0, NetlinkPayload::Ack(ack_message)), kernel());
+        assert!(protocol.incoming_responses.is_empty());
+        assert!(protocol.incoming_requests.is_empty());
+
+        // failing requests when none is pending does nothing
+        protocol.fail_requests(-libc::ENOBUFS);
+        assert!(protocol.incoming_responses.is_empty());
+    }
+
+    #[test]
+    fn deadlines_order() {
+        let mut protocol = Protocol::new();
+        assert_eq!(protocol.next_deadline(), None);
+
+        let before = Instant::now();
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+        request(&mut protocol, flags, Some(Duration::from_secs(30)), 1);
+        let first = request(&mut protocol, flags, Some(Duration::from_secs(10)), 2);
+        request(&mut protocol, flags, None, 3);
+        request(&mut protocol, flags, Some(Duration::from_secs(20)), 4);
+        let after = Instant::now();
+
+        let deadline = protocol.next_deadline().unwrap();
+        assert!(deadline >= before + Duration::from_secs(10));
+        assert!(deadline <= after + Duration::from_secs(10));
+
+        // only the request with the earliest deadline expires
+        protocol.expire_requests(after + Duration::from_secs(15));
+        assert_eq!(protocol.incoming_responses.len(), 1);
+        let response = protocol.incoming_responses.pop_front().unwrap();
+        assert!(response.done);
+        assert_eq!(response.metadata, 2);
+        assert_eq!(response.message.header.sequence_number, first);
+        assert_eq!(error_code(&response), -libc::ETIMEDOUT);
+
+        let deadline = protocol.next_deadline().unwrap();
+        assert!(deadline >= before + Duration::from_secs(20));
+        assert!(deadline <= after + Duration::from_secs(20));
+        assert_eq!(protocol.pending_requests.len(), 3);
+    }
+
+    #[test]
+    fn answered_request_deadline() {
+        let mut protocol = Protocol::new();
+        protocol.set_request_timeout(Some(Duration::from_secs(10)));
+        let ack = request(&mut protocol, NLM_F_REQUEST | NLM_F_ACK, None, 1);
+        assert!(protocol.next_deadline().is_some());
+
+        let ack_message = ErrorMessage::new(0, vec![]);
+        protocol.handle_message(response(ack, 0, NetlinkPayload::Ack(ack_message)), kernel());
+        assert_eq!(protocol.next_deadline(), None);
+        protocol.expire_requests(Instant::now() + Duration::from_secs(20));
+        assert_eq!(protocol.incoming_responses.len(), 1);
+    }
+
+    #[test]
+    fn late_responses() {
+        let mut protocol = Protocol::new();
+        let dump = request(
+            &mut protocol,
+            NLM_F_REQUEST | NLM_F_DUMP,
+            Some(Duration::from_secs(1)),
+            1,
+        );
+        let now = Instant::now() + Duration::from_secs(2);
+        protocol.expire_requests(now);
+        let response_to_dump = protocol.incoming_responses.pop_front().unwrap();
+        assert_eq!(error_code(&response_to_dump), -libc::ETIMEDOUT);
+
+        // data messages do not end the dump, so more of them may come
+        protocol.handle_message(link(dump), kernel());
+        protocol.handle_message(link(dump), kernel());
+        protocol.handle_message(response(dump, 0, NetlinkPayload::Done), kernel());
+        assert!(protocol.incoming_responses.is_empty());
+        assert!(protocol.incoming_requests.is_empty());
+        assert!(protocol.aborted_requests.is_empty());
+
+        // past the end of the dump, messages with the same sequence number are not responses
+        protocol.handle_message(link(dump), kernel());
+        assert_eq!(protocol.incoming_requests.len(), 1);
+    }
+
+    #[test]
+    fn late_responses_delay() {
+        let mut protocol = Protocol::new();
+        let dump = request(
+            &mut protocol,
+            NLM_F_REQUEST | NLM_F_DUMP,
+            Some(Duration::from_secs(1)),
+            1,
+        );
+        let now = Instant::now() + Duration::from_secs(2);
+        protocol.expire_requests(now);
+        assert_eq!(protocol.aborted_requests.len(), 1);
+
+        protocol.expire_requests(now + LATE_RESPONSES_DELAY - Duration::from_secs(1));
+        protocol.handle_message(link(dump), kernel());
+        assert_eq!(protocol.aborted_requests.len(), 1);
+        assert!(protocol.incoming_requests.is_empty());
+
+        protocol.expire_requests(now + LATE_RESPONSES_DELAY + Duration::from_secs(1));
+        assert!(protocol.aborted_requests.is_empty());
+        protocol.handle_message(link(dump), kernel());
+        assert_eq!(protocol.incoming_requests.len(), 1);
+    }
+
+    #[test]
+    fn fail_request() {
+        let mut protocol = Protocol::new();
+        let dump = request(&mut protocol, NLM_F_REQUEST | NLM_F_DUMP, None, 1);
+        let ack = request(&mut protocol, NLM_F_REQUEST | NLM_F_ACK, None, 2);
+
+        // a message that does not answer a pending request
+        assert!(!protocol.fail_request(ack + 1, kernel(), -libc::EMSGSIZE));
+        assert!(!protocol.fail_request(ack, SocketAddr::new(42, 0), -libc::EMSGSIZE));
+        assert!(protocol.incoming_responses.is_empty());
+
+        assert!(protocol.fail_request(dump, kernel(), -libc::EMSGSIZE));
+        let response = protocol.incoming_responses.pop_front().unwrap();
+        assert!(response.done);
+        assert_eq!(response.metadata, 1);
+        assert_eq!(error_code(&response), -libc::EMSGSIZE);
+
+        // the other request is still pending
+        assert_eq!(protocol.pending_requests.len(), 1);
+        assert!(!protocol.fail_request(dump, kernel(), -libc::EMSGSIZE));
+    }
+}
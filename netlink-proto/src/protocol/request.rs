@@ -1,6 +1,6 @@
 use netlink_packet_core::NetlinkMessage;
 use netlink_sys::SocketAddr;
-use std::fmt::Debug;
+use std::{fmt::Debug, time::Duration};
 
 #[derive(Debug)]
 pub struct Request<T, M>
@@ -11,6 +11,12 @@ where
     pub metadata: M,
     pub message: NetlinkMessage<T>,
     pub destination: SocketAddr,
+    /// How long to wait for the request to be fully answered. If
+    /// `None`, the connection's default timeout applies.
+    pub timeout: Option<Duration>,
+    /// Whether the message is part of a batch, in which case it can be
+    /// sent in the same datagram as the previous batched messages.
+    pub batched: bool,
 }
 
 impl<T, M> From<(NetlinkMessage<T>, SocketAddr, M)> for Request<T, M>
@@ -23,6 +29,8 @@ where
             message: parts.0,
             destination: parts.1,
             metadata: parts.2,
+            timeout: None,
+            batched: false,
         }
     }
 }
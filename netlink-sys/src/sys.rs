@@ -385,6 +385,69 @@ impl Socket {
         let res = getsockopt::<libc::c_int>(self.0, libc::SOL_NETLINK, libc::NETLINK_CAP_ACK)?;
         Ok(res == 1)
     }
+
+    /// `NETLINK_EXT_ACK` (since Linux 4.12). When set, the kernel appends extended ACK attributes
+    /// to error and acknowledgment messages, like a human readable error string and the offset
+    /// of the attribute that caused the error.
+    pub fn set_ext_ack(&mut self, value: bool) -> Result<()> {
+        let value: libc::c_int = if value { 1 } else { 0 };
+        setsockopt(self.0, libc::SOL_NETLINK, libc::NETLINK_EXT_ACK, value)
+    }
+
+    pub fn get_ext_ack(&self) -> Result<bool> {
+        let res = getsockopt::<libc::c_int>(self.0, libc::SOL_NETLINK, libc::NETLINK_EXT_ACK)?;
+        Ok(res == 1)
+    }
+
+    /// `NETLINK_GET_STRICT_CHK` (since Linux 4.20). When set, the kernel strictly validates the
+    /// header and attributes of dump requests, and uses them to filter the dumped objects
+    /// (links, addresses, routes, neighbours...). Requests with unsupported values are rejected
+    /// with `EINVAL` instead of being silently accepted.
+    pub fn set_strict_checking(&mut self, value: bool) -> Result<()> {
+        let value: libc::c_int = if value { 1 } else { 0 };
+        setsockopt(
+            self.0,
+            libc::SOL_NETLINK,
+            libc::NETLINK_GET_STRICT_CHK,
+            value,
+        )
+    }
+
+    pub fn get_strict_checking(&self) -> Result<bool> {
+        let res =
+            getsockopt::<libc::c_int>(self.0, libc::SOL_NETLINK, libc::NETLINK_GET_STRICT_CHK)?;
+        Ok(res == 1)
+    }
+
+    /// `SO_RCVBUF`: set the size of the socket receive buffer, in bytes. The kernel doubles this
+    /// value to account for its bookkeeping overhead, and caps it to `net.core.rmem_max`. When
+    /// the buffer is full, the kernel drops messages and reading from the socket fails with
+    /// `ENOBUFS`.
+    pub fn set_rx_buf_sz(&mut self, size: usize) -> Result<()> {
+        setsockopt(
+            self.0,
+            libc::SOL_SOCKET,
+            libc::SO_RCVBUF,
+            size as libc::c_int,
+        )
+    }
+
+    /// `SO_RCVBUFFORCE`: same as [`set_rx_buf_sz`](#method.set_rx_buf_sz), but the
+    /// `net.core.rmem_max` limit does not apply. This requires the `CAP_NET_ADMIN` capability.
+    pub fn set_rx_buf_sz_force(&mut self, size: usize) -> Result<()> {
+        setsockopt(
+            self.0,
+            libc::SOL_SOCKET,
+            libc::SO_RCVBUFFORCE,
+            size as libc::c_int,
+        )
+    }
+
+    /// Return the size of the socket receive buffer, in bytes
+    pub fn get_rx_buf_sz(&self) -> Result<usize> {
+        let res = getsockopt::<libc::c_int>(self.0, libc::SOL_SOCKET, libc::SO_RCVBUF)?;
+        Ok(res as usize)
+    }
 }
 
 /// Wrapper around `getsockopt`:
@@ -485,6 +548,19 @@ mod test {
         sock.set_no_enobufs(false).unwrap();
         assert!(!sock.get_no_enobufs().unwrap());
 
+        sock.set_ext_ack(true).unwrap();
+        assert!(sock.get_ext_ack().unwrap());
+        sock.set_ext_ack(false).unwrap();
+        assert!(!sock.get_ext_ack().unwrap());
+
+        sock.set_strict_checking(true).unwrap();
+        assert!(sock.get_strict_checking().unwrap());
+        sock.set_strict_checking(false).unwrap();
+        assert!(!sock.get_strict_checking().unwrap());
+
+        sock.set_rx_buf_sz(64 * 1024).unwrap();
+        assert!(sock.get_rx_buf_sz().unwrap() >= 64 * 1024);
+
         sock.set_broadcast_error(true).unwrap();
         assert!(sock.get_broadcast_error().unwrap());
         sock.set_broadcast_error(false).unwrap();
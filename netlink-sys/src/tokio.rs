@@ -107,12 +107,24 @@ impl Socket {
         &mut self,
         cx: &mut Context,
         buf: &mut [u8],
+    ) -> Poll<io::Result<(usize, SocketAddr)>> {
+        self.poll_recv_from_with_flags(cx, buf, 0)
+    }
+
+    /// Same as [`poll_recv_from`](#method.poll_recv_from), but with the given `recvfrom` flags.
+    /// For instance, with `MSG_PEEK | MSG_TRUNC`, the next datagram is not consumed and its
+    /// actual length is returned even if it does not fit in `buf`.
+    pub fn poll_recv_from_with_flags(
+        &mut self,
+        cx: &mut Context,
+        buf: &mut [u8],
+        flags: libc::c_int,
     ) -> Poll<io::Result<(usize, SocketAddr)>> {
         trace!("poll_recv_from called");
         ready!(self.0.poll_read_ready(cx, mio::Ready::readable()))?;
 
         trace!("poll_recv_from socket is ready for reading");
-        match self.0.get_ref().recv_from(buf, 0) {
+        match self.0.get_ref().recv_from(buf, flags) {
             Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => {
                 trace!("poll_recv_from socket would block");
                 self.0.clear_read_ready(cx, mio::Ready::readable())?;
@@ -187,4 +199,46 @@ impl Socket {
     pub fn get_cap_ack(&self) -> io::Result<bool> {
         self.0.get_ref().get_cap_ack()
     }
+
+    /// `NETLINK_EXT_ACK` (since Linux 4.12). When set, the kernel appends extended ACK attributes
+    /// to error and acknowledgment messages, like a human readable error string and the offset
+    /// of the attribute that caused the error.
+    pub fn set_ext_ack(&mut self, value: bool) -> io::Result<()> {
+        self.0.get_mut().set_ext_ack(value)
+    }
+
+    pub fn get_ext_ack(&self) -> io::Result<bool> {
+        self.0.get_ref().get_ext_ack()
+    }
+
+    /// `NETLINK_GET_STRICT_CHK` (since Linux 4.20). When set, the kernel strictly validates the
+    /// header and attributes of dump requests, and uses them to filter the dumped objects
+    /// (links, addresses, routes, neighbours...). Requests with unsupported values are rejected
+    /// with `EINVAL` instead of being silently accepted.
+    pub fn set_strict_checking(&mut self, value: bool) -> io::Result<()> {
+        self.0.get_mut().set_strict_checking(value)
+    }
+
+    pub fn get_strict_checking(&self) -> io::Result<bool> {
+        self.0.get_ref().get_strict_checking()
+    }
+
+    /// `SO_RCVBUF`: set the size of the socket receive buffer, in bytes. The kernel doubles this
+    /// value to account for its bookkeeping overhead, and caps it to `net.core.rmem_max`. When
+    /// the buffer is full, the kernel drops messages and reading from the socket fails with
+    /// `ENOBUFS`.
+    pub fn set_rx_buf_sz(&mut self, size: usize) -> io::Result<()> {
+        self.0.get_mut().set_rx_buf_sz(size)
+    }
+
+    /// `SO_RCVBUFFORCE`: same as [`set_rx_buf_sz`](#method.set_rx_buf_sz), but the
+    /// `net.core.rmem_max` limit does not apply. This requires the `CAP_NET_ADMIN` capability.
+    pub fn set_rx_buf_sz_force(&mut self, size: usize) -> io::Result<()> {
+        self.0.get_mut().set_rx_buf_sz_force(size)
+    }
+
+    /// Return the size of the socket receive buffer, in bytes
+    pub fn get_rx_buf_sz(&self) -> io::Result<usize> {
+        self.0.get_ref().get_rx_buf_sz()
+    }
 }
@@ -3,29 +3,22 @@
 
 use futures::stream::StreamExt;
 
-use rtnetlink::{
-    constants::{RTMGRP_IPV4_ROUTE, RTMGRP_IPV6_ROUTE},
-    new_connection,
-    sys::SocketAddr,
-};
+use rtnetlink::{new_connection_with_groups, MulticastGroup, Notification};
 
 #[tokio::main]
 async fn main() -> Result<(), String> {
-    // Open the netlink socket
-    let (mut connection, _, mut messages) = new_connection().map_err(|e| format!("{}", e))?;
-
-    // These flags specify what kinds of broadcast messages we want to listen for.
-    let mgroup_flags = RTMGRP_IPV4_ROUTE | RTMGRP_IPV6_ROUTE;
-
-    // A netlink socket address is created with said flags.
-    let addr = SocketAddr::new(0, mgroup_flags);
-    // Said address is bound so new conenctions and thus new message broadcasts can be received.
-    connection.socket_mut().bind(&addr).expect("failed to bind");
+    // Open the netlink socket, subscribed to the multicast groups we want to receive broadcast
+    // messages for.
+    let (connection, _, mut messages) =
+        new_connection_with_groups(&[MulticastGroup::Ipv4Route, MulticastGroup::Ipv6Route])
+            .map_err(|e| format!("{}", e))?;
     tokio::spawn(connection);
 
-    while let Some((message, _)) = messages.next().await {
-        let payload = message.payload;
-        println!("Route change message - {:?}", payload);
+    while let Some(notification) = messages.next().await {
+        match notification {
+            Notification::Message(message) => println!("Route change message - {:?}", message),
+            Notification::Overrun => println!("Notifications were lost"),
+        }
     }
     Ok(())
 }
@@ -0,0 +1,15 @@
+//! This example tracks the links and addresses of the host, and prints every change.
+
+use futures::stream::TryStreamExt;
+
+use rtnetlink::{ResourceKind, Watcher};
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+    let mut watcher = Watcher::new(&[ResourceKind::Link, ResourceKind::Address]);
+    while let Some(event) = watcher.try_next().await.map_err(|e| format!("{}", e))? {
+        println!("{:?}", event);
+        println!("{} links known", watcher.links().len());
+    }
+    Ok(())
+}
@@ -6,7 +6,7 @@ use netlink_packet_route::{
     AF_INET6, NLM_F_ACK, NLM_F_CREATE, NLM_F_EXCL, NLM_F_REQUEST,
 };
 
-use crate::{Error, ErrorKind, Handle};
+use crate::{BatchMessage, Error, ErrorKind, Handle};
 
 /// A request to create a new address. This is equivalent to the `ip address add` commands.
 pub struct AddressAddRequest {
@@ -67,8 +67,7 @@ impl AddressAddRequest {
             mut handle,
             message,
         } = self;
-        let mut req = NetlinkMessage::from(RtnlMessage::NewAddress(message));
-        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE;
+        let req = add_address_message(message);
 
         let mut response = handle.request(req)?;
         while let Some(message) = response.next().await {
@@ -84,3 +83,15 @@ impl AddressAddRequest {
         &mut self.message
     }
 }
+
+impl From<AddressAddRequest> for BatchMessage {
+    fn from(request: AddressAddRequest) -> Self {
+        BatchMessage::new(add_address_message(request.message))
+    }
+}
+
+fn add_address_message(message: AddressMessage) -> NetlinkMessage<RtnlMessage> {
+    let mut req = NetlinkMessage::from(RtnlMessage::NewAddress(message));
+    req.header.flags = NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE;
+    req
+}
@@ -4,7 +4,7 @@ use crate::{
     packet::{
         AddressMessage, NetlinkMessage, NetlinkPayload, RtnlMessage, NLM_F_ACK, NLM_F_REQUEST,
     },
-    Error, ErrorKind, Handle,
+    BatchMessage, Error, ErrorKind, Handle,
 };
 
 pub struct AddressDelRequest {
@@ -23,9 +23,7 @@ impl AddressDelRequest {
             mut handle,
             message,
         } = self;
-
-        let mut req = NetlinkMessage::from(RtnlMessage::DelAddress(message));
-        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+        let req = del_address_message(message);
         let mut response = handle.request(req)?;
         while let Some(msg) = response.next().await {
             if let NetlinkPayload::Error(e) = msg.payload {
@@ -39,3 +37,15 @@ impl AddressDelRequest {
         &mut self.message
     }
 }
+
+impl From<AddressDelRequest> for BatchMessage {
+    fn from(request: AddressDelRequest) -> Self {
+        BatchMessage::new(del_address_message(request.message))
+    }
+}
+
+fn del_address_message(message: AddressMessage) -> NetlinkMessage<RtnlMessage> {
+    let mut req = NetlinkMessage::from(RtnlMessage::DelAddress(message));
+    req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+    req
+}
@@ -62,8 +62,10 @@ impl AddressGetRequest {
         }
     }
 
-    /// Return only the addresses of the given interface.
+    /// Return only the addresses of the given interface. If strict checking is enabled on the
+    /// socket (see `Socket::set_strict_checking`), the kernel only dumps these addresses.
     pub fn set_link_index_filter(mut self, index: u32) -> Self {
+        self.message.header.index = index;
         self.filter_builder.index = Some(index);
         self
     }
@@ -82,7 +84,9 @@ impl AddressGetRequest {
 }
 
 // The reason for having filters, is that we cannot retrieve addresses
-// that match the given message, like we do for links.
+// that match the given message, like we do for links. With strict
+// checking, the kernel can filter on the interface index, but older
+// kernels and sockets without strict checking ignore it.
 //
 // See:
 // https://lists.infradead.org/pipermail/libnl/2013-June/001014.html
@@ -0,0 +1,257 @@
+use futures::stream::{Stream, StreamExt};
+use std::fs::File;
+
+use crate::{
+    packet::{
+        NetlinkMessage, NetlinkPayload, RtnlMessage, NLM_F_ACK, NLM_F_CREATE, NLM_F_EXCL,
+        NLM_F_REQUEST,
+    },
+    Error, ErrorKind, Handle,
+};
+
+/// A request that can be added to a [`BatchRequest`](struct.BatchRequest.html). It is built from
+/// the link, address and route requests, like [`LinkAddRequest`](struct.LinkAddRequest.html) or
+/// [`RouteDelRequest`](struct.RouteDelRequest.html).
+pub struct BatchMessage {
+    pub(crate) message: Result<NetlinkMessage<RtnlMessage>, ErrorKind>,
+    // Network namespace a link is moved to. The file must stay open until the kernel has
+    // processed the request.
+    pub(crate) netns: Option<File>,
+}
+
+impl BatchMessage {
+    pub(crate) fn new(message: NetlinkMessage<RtnlMessage>) -> Self {
+        BatchMessage {
+            message: Ok(message),
+            netns: None,
+        }
+    }
+}
+
+/// A list of requests sent to the kernel at once. This is much faster than executing the
+/// requests one by one when there are many of them, for instance to create thousands of routes.
+pub struct BatchRequest {
+    handle: Handle,
+    messages: Vec<NetlinkMessage<RtnlMessage>>,
+    netns: Vec<File>,
+    // Error detected while building the batch, returned when the batch is executed.
+    error: Option<ErrorKind>,
+}
+
+impl BatchRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        BatchRequest {
+            handle,
+            messages: vec![],
+            netns: vec![],
+            error: None,
+        }
+    }
+
+    /// Add a request built with one of the link, address or route handles. The request is not
+    /// executed on its own: it is sent with the rest of the batch.
+    ///
+    /// ```rust,no_run
+    /// use rtnetlink::Handle;
+    ///
+    /// async fn run(handle: Handle) -> Result<(), rtnetlink::Error> {
+    ///     let results = handle
+    ///         .batch()
+    ///         .request(handle.link().set(2).up())
+    ///         .request(handle.link().set(3).up())
+    ///         .execute()
+    ///         .await?;
+    ///     for result in results {
+    ///         result?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn request<R: Into<BatchMessage>>(mut self, request: R) -> Self {
+        let BatchMessage { message, netns } = request.into();
+        match message {
+            Ok(message) => self.messages.push(message),
+            Err(error) => {
+                if self.error.is_none() {
+                    self.error = Some(error);
+                }
+            }
+        }
+        self.netns.extend(netns);
+        self
+    }
+
+    /// Add a request that creates an object, like `RtnlMessage::NewRoute`. It fails if the
+    /// object already exists.
+    pub fn create(self, message: RtnlMessage) -> Self {
+        self.message(message, NLM_F_CREATE | NLM_F_EXCL)
+    }
+
+    /// Add a request that deletes an object, like `RtnlMessage::DelRoute`
+    pub fn delete(self, message: RtnlMessage) -> Self {
+        self.message(message, 0)
+    }
+
+    /// Add a request with the given flags (`NLM_F_REPLACE` for instance). `NLM_F_REQUEST` and
+    /// `NLM_F_ACK` are always set.
+    pub fn message(self, message: RtnlMessage, flags: u16) -> Self {
+        let mut req = NetlinkMessage::from(message);
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | flags;
+        self.request(BatchMessage::new(req))
+    }
+
+    /// Execute the requests. The result of each request is returned, in the same order as the
+    /// requests were added. A failed request does not prevent the following ones from being
+    /// executed.
+    pub async fn execute(self) -> Result<Vec<Result<(), Error>>, Error> {
+        let BatchRequest {
+            mut handle,
+            messages,
+            netns: _netns,
+            error,
+        } = self;
+        if let Some(error) = error {
+            return Err(error.into());
+        }
+
+        let responses = handle.request_batch(messages)?;
+        Ok(batch_results(responses).await)
+    }
+}
+
+/// Wait for the response to each request of a batch, and return the result of each request.
+async fn batch_results<S>(responses: Vec<S>) -> Vec<Result<(), Error>>
+where
+    S: Stream<Item = NetlinkMessage<RtnlMessage>> + Unpin,
+{
+    let mut results = Vec::with_capacity(responses.len());
+    for mut response in responses {
+        let mut result = Ok(());
+        while let Some(msg) = response.next().await {
+            if let NetlinkPayload::Error(e) = msg.payload {
+                result = Err(ErrorKind::NetlinkError(e).into());
+            }
+        }
+        results.push(result);
+    }
+    results
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        new_connection,
+        packet::{
+            ErrorMessage, NetlinkHeader, RouteMessage, RTM_DELLINK, RTM_DELROUTE, RTM_NEWADDR,
+            RTM_NEWROUTE,
+        },
+    };
+    use futures::{executor::block_on, stream};
+    use std::net::{IpAddr, Ipv4Addr};
+
+    fn error(code: i32) -> NetlinkMessage<RtnlMessage> {
+        NetlinkMessage::new(
+            NetlinkHeader::default(),
+            NetlinkPayload::Error(ErrorMessage::new(code, vec![])),
+        )
+    }
+
+    fn ack() -> NetlinkMessage<RtnlMessage> {
+        NetlinkMessage::new(
+            NetlinkHeader::default(),
+            NetlinkPayload::Ack(ErrorMessage::new(0, vec![])),
+        )
+    }
+
+    #[test]
+    fn one_result_per_request() {
+        let responses = vec![
+            stream::iter(vec![ack()]),
+            stream::iter(vec![error(-libc::EEXIST)]),
+            stream::iter(vec![]),
+            stream::iter(vec![ack()]),
+        ];
+        let results = block_on(batch_results(responses));
+        assert_eq!(results.len(), 4);
+        assert!(results[0].is_ok());
+        match results[1].as_ref().unwrap_err().kind() {
+            ErrorKind::NetlinkError(e) => assert_eq!(e.code, -libc::EEXIST),
+            kind => panic!("unexpected error {:?}", kind),
+        }
+        assert!(results[2].is_ok());
+        assert!(results[3].is_ok());
+    }
+
+    #[tokio::test]
+    async fn add_requests() {
+        let (connection, handle, _) = new_connection().unwrap();
+        tokio::spawn(connection);
+
+        let batch = handle
+            .batch()
+            .request(handle.link().del(1))
+            .request(
+                handle
+                    .address()
+                    .add(1, IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)), 24),
+            )
+            .request(handle.route().add_v4().output_interface(1))
+            .delete(RtnlMessage::DelRoute(RouteMessage::default()));
+        let types: Vec<u16> = batch
+            .messages
+            .iter()
+            .map(|message| message.payload.message_type())
+            .collect();
+        assert_eq!(
+            types,
+            vec![RTM_DELLINK, RTM_NEWADDR, RTM_NEWROUTE, RTM_DELROUTE]
+        );
+        assert!(batch
+            .messages
+            .iter()
+            .all(|message| message.header.flags & NLM_F_ACK != 0));
+    }
+
+    #[tokio::test]
+    async fn execute_returns_one_result_per_request() {
+        let (connection, handle, _) = new_connection().unwrap();
+        tokio::spawn(connection);
+
+        // Links that do not exist: every request fails, whether we have the permission to
+        // delete links or not.
+        let results = handle
+            .batch()
+            .request(handle.link().del(u32::MAX - 2))
+            .request(handle.link().del(u32::MAX - 1))
+            .request(handle.link().del(u32::MAX))
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(Result::is_err));
+    }
+
+    #[tokio::test]
+    async fn execute_returns_request_error() {
+        let (connection, handle, _) = new_connection().unwrap();
+        tokio::spawn(connection);
+
+        let err = handle
+            .batch()
+            .request(handle.link().del(u32::MAX))
+            .request(
+                handle
+                    .link()
+                    .set(u32::MAX)
+                    .setns_by_path("/nonexistent/netns"),
+            )
+            .execute()
+            .await
+            .unwrap_err();
+        match err.kind() {
+            ErrorKind::InvalidNetns(..) => {}
+            kind => panic!("unexpected error {:?}", kind),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use futures::stream::StreamExt;
+
+use super::af_spec::{add_bridge_flags, add_vlan_flags, set_vlan_range_end, vlan_message};
+use crate::{
+    packet::{
+        LinkMessage, NetlinkMessage, NetlinkPayload, RtnlMessage, BRIDGE_FLAGS_MASTER,
+        BRIDGE_FLAGS_SELF, BRIDGE_VLAN_INFO_PVID, BRIDGE_VLAN_INFO_UNTAGGED, NLM_F_ACK,
+        NLM_F_REQUEST,
+    },
+    Error, ErrorKind, Handle,
+};
+
+/// A request to add a VLAN to a bridge port. This is equivalent to the `bridge vlan add`
+/// command.
+pub struct BridgeVlanAddRequest {
+    handle: Handle,
+    message: LinkMessage,
+}
+
+impl BridgeVlanAddRequest {
+    pub(crate) fn new(handle: Handle, index: u32, vid: u16) -> Self {
+        BridgeVlanAddRequest {
+            handle,
+            message: vlan_message(index, vid),
+        }
+    }
+
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let BridgeVlanAddRequest {
+            mut handle,
+            message,
+        } = self;
+        let mut req = NetlinkMessage::from(RtnlMessage::SetLink(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            if let NetlinkPayload::Error(err) = message.payload {
+                return Err(ErrorKind::NetlinkError(err).into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut LinkMessage {
+        &mut self.message
+    }
+
+    /// Use this VLAN as the PVID of the port, i.e. untagged ingress traffic is assigned to it.
+    /// This is equivalent to the `pvid` option of `bridge vlan add`.
+    pub fn pvid(mut self) -> Self {
+        add_vlan_flags(&mut self.message, BRIDGE_VLAN_INFO_PVID);
+        self
+    }
+
+    /// Send egress traffic for this VLAN untagged. This is equivalent to the `untagged` option
+    /// of `bridge vlan add`.
+    pub fn untagged(mut self) -> Self {
+        add_vlan_flags(&mut self.message, BRIDGE_VLAN_INFO_UNTAGGED);
+        self
+    }
+
+    /// Add all the VLANs from the one given to the handle up to `vid_end` (included). This is
+    /// equivalent to `bridge vlan add dev DEV vid VID-VID_END`.
+    pub fn range_end(mut self, vid_end: u16) -> Self {
+        set_vlan_range_end(&mut self.message, vid_end);
+        self
+    }
+
+    /// Configure the VLAN on the bridge the port belongs to. This is equivalent to the `master`
+    /// option of `bridge vlan add`, which is the default.
+    pub fn on_master(mut self) -> Self {
+        add_bridge_flags(&mut self.message, BRIDGE_FLAGS_MASTER);
+        self
+    }
+
+    /// Configure the VLAN on the device itself instead of its master. This is equivalent to the
+    /// `self` option of `bridge vlan add`. To add a VLAN to the bridge device itself, pass the
+    /// index of the bridge to the handle and use this option.
+    pub fn on_self(mut self) -> Self {
+        add_bridge_flags(&mut self.message, BRIDGE_FLAGS_SELF);
+        self
+    }
+}
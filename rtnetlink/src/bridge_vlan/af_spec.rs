@@ -0,0 +1,79 @@
+use crate::packet::{
+    nlas::link::{AfSpecBridge, BridgeVlanInfo, Nla},
+    LinkMessage, AF_BRIDGE, BRIDGE_VLAN_INFO_RANGE_BEGIN, BRIDGE_VLAN_INFO_RANGE_END,
+};
+
+/// Build an `AF_BRIDGE` link message carrying a single VLAN entry, as expected by the kernel for
+/// `RTM_SETLINK` and `RTM_DELLINK` bridge VLAN requests.
+pub(crate) fn vlan_message(index: u32, vid: u16) -> LinkMessage {
+    let mut message = LinkMessage::default();
+    message.header.interface_family = AF_BRIDGE as u8;
+    message.header.index = index;
+    message
+        .nlas
+        .push(Nla::AfSpecBridge(vec![AfSpecBridge::VlanInfo(
+            BridgeVlanInfo { flags: 0, vid },
+        )]));
+    message
+}
+
+fn af_spec_mut(message: &mut LinkMessage) -> &mut Vec<AfSpecBridge> {
+    let index = match message
+        .nlas
+        .iter()
+        .position(|nla| matches!(nla, Nla::AfSpecBridge(_)))
+    {
+        Some(index) => index,
+        None => {
+            message.nlas.push(Nla::AfSpecBridge(vec![]));
+            message.nlas.len() - 1
+        }
+    };
+    match message.nlas[index] {
+        Nla::AfSpecBridge(ref mut nlas) => nlas,
+        _ => unreachable!(),
+    }
+}
+
+/// Add the given `BRIDGE_FLAGS_*` flags to the IFLA_BRIDGE_FLAGS attribute of the message.
+pub(crate) fn add_bridge_flags(message: &mut LinkMessage, flags: u16) {
+    let nlas = af_spec_mut(message);
+    for nla in nlas.iter_mut() {
+        if let AfSpecBridge::Flags(ref mut value) = nla {
+            *value |= flags;
+            return;
+        }
+    }
+    nlas.insert(0, AfSpecBridge::Flags(flags));
+}
+
+/// Add the given `BRIDGE_VLAN_INFO_*` flags to all the VLAN entries of the message.
+pub(crate) fn add_vlan_flags(message: &mut LinkMessage, flags: u16) {
+    for nla in af_spec_mut(message).iter_mut() {
+        if let AfSpecBridge::VlanInfo(ref mut info) = nla {
+            info.flags |= flags;
+        }
+    }
+}
+
+/// Turn the VLAN entry of the message into a range ending at `vid_end`.
+pub(crate) fn set_vlan_range_end(message: &mut LinkMessage, vid_end: u16) {
+    let nlas = af_spec_mut(message);
+    nlas.retain(|nla| match nla {
+        AfSpecBridge::VlanInfo(info) => info.flags & BRIDGE_VLAN_INFO_RANGE_END == 0,
+        _ => true,
+    });
+    let mut end = None;
+    for nla in nlas.iter_mut() {
+        if let AfSpecBridge::VlanInfo(ref mut info) = nla {
+            info.flags |= BRIDGE_VLAN_INFO_RANGE_BEGIN;
+            end = Some(BridgeVlanInfo {
+                flags: (info.flags & !BRIDGE_VLAN_INFO_RANGE_BEGIN) | BRIDGE_VLAN_INFO_RANGE_END,
+                vid: vid_end,
+            });
+        }
+    }
+    if let Some(end) = end {
+        nlas.push(AfSpecBridge::VlanInfo(end));
+    }
+}
@@ -0,0 +1,70 @@
+use futures::stream::StreamExt;
+
+use super::af_spec::{add_bridge_flags, set_vlan_range_end, vlan_message};
+use crate::{
+    packet::{
+        LinkMessage, NetlinkMessage, NetlinkPayload, RtnlMessage, BRIDGE_FLAGS_MASTER,
+        BRIDGE_FLAGS_SELF, NLM_F_ACK, NLM_F_REQUEST,
+    },
+    Error, ErrorKind, Handle,
+};
+
+/// A request to remove a VLAN from a bridge port. This is equivalent to the `bridge vlan del`
+/// command.
+pub struct BridgeVlanDelRequest {
+    handle: Handle,
+    message: LinkMessage,
+}
+
+impl BridgeVlanDelRequest {
+    pub(crate) fn new(handle: Handle, index: u32, vid: u16) -> Self {
+        BridgeVlanDelRequest {
+            handle,
+            message: vlan_message(index, vid),
+        }
+    }
+
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let BridgeVlanDelRequest {
+            mut handle,
+            message,
+        } = self;
+        let mut req = NetlinkMessage::from(RtnlMessage::DelLink(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            if let NetlinkPayload::Error(err) = message.payload {
+                return Err(ErrorKind::NetlinkError(err).into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut LinkMessage {
+        &mut self.message
+    }
+
+    /// Remove all the VLANs from the one given to the handle up to `vid_end` (included). This is
+    /// equivalent to `bridge vlan del dev DEV vid VID-VID_END`.
+    pub fn range_end(mut self, vid_end: u16) -> Self {
+        set_vlan_range_end(&mut self.message, vid_end);
+        self
+    }
+
+    /// Remove the VLAN from the bridge the port belongs to. This is equivalent to the `master`
+    /// option of `bridge vlan del`, which is the default.
+    pub fn on_master(mut self) -> Self {
+        add_bridge_flags(&mut self.message, BRIDGE_FLAGS_MASTER);
+        self
+    }
+
+    /// Remove the VLAN from the device itself instead of its master. This is equivalent to the
+    /// `self` option of `bridge vlan del`.
+    pub fn on_self(mut self) -> Self {
+        add_bridge_flags(&mut self.message, BRIDGE_FLAGS_SELF);
+        self
+    }
+}
@@ -0,0 +1,97 @@
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream, TryStreamExt},
+    FutureExt,
+};
+
+use crate::{
+    packet::{
+        nlas::link::Nla, LinkMessage, NetlinkMessage, NetlinkPayload, RtnlMessage, AF_BRIDGE,
+        NLM_F_DUMP, NLM_F_REQUEST, RTEXT_FILTER_BRVLAN, RTEXT_FILTER_BRVLAN_COMPRESSED,
+    },
+    Error, ErrorKind, Handle,
+};
+
+/// A request to dump the VLANs of the bridges and bridge ports. This is equivalent to the
+/// `bridge vlan show` command.
+///
+/// The VLANs are returned as `AfSpecBridge::VlanInfo` entries in the `Nla::AfSpecBridge`
+/// attribute of each link message.
+pub struct BridgeVlanGetRequest {
+    handle: Handle,
+    message: LinkMessage,
+    index: Option<u32>,
+}
+
+impl BridgeVlanGetRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        let mut message = LinkMessage::default();
+        message.header.interface_family = AF_BRIDGE as u8;
+        message.nlas.push(Nla::ExtMask(RTEXT_FILTER_BRVLAN));
+        BridgeVlanGetRequest {
+            handle,
+            message,
+            index: None,
+        }
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl TryStream<Ok = LinkMessage, Error = Error> {
+        let BridgeVlanGetRequest {
+            mut handle,
+            message,
+            index,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::GetLink(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+
+        match handle.request(req) {
+            Ok(response) => Either::Left(
+                response
+                    .map(move |msg| {
+                        let (header, payload) = msg.into_parts();
+                        match payload {
+                            NetlinkPayload::InnerMessage(RtnlMessage::NewLink(msg)) => Ok(msg),
+                            NetlinkPayload::Error(err) => Err(ErrorKind::NetlinkError(err).into()),
+                            _ => Err(ErrorKind::UnexpectedMessage(NetlinkMessage::new(
+                                header, payload,
+                            ))
+                            .into()),
+                        }
+                    })
+                    .try_filter(move |msg| {
+                        let matches = match index {
+                            Some(index) => msg.header.index == index,
+                            None => true,
+                        };
+                        future::ready(matches)
+                    }),
+            ),
+            Err(e) => Either::Right(future::err::<LinkMessage, Error>(e).into_stream()),
+        }
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut LinkMessage {
+        &mut self.message
+    }
+
+    /// Only return the VLANs of the link with the given index
+    pub fn match_index(mut self, index: u32) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Ask the kernel to report consecutive VLANs with the same flags as ranges
+    /// (`BRIDGE_VLAN_INFO_RANGE_BEGIN` and `BRIDGE_VLAN_INFO_RANGE_END` entries) instead of one
+    /// entry per VLAN.
+    pub fn compressed(mut self) -> Self {
+        for nla in self.message.nlas.iter_mut() {
+            if let Nla::ExtMask(ref mut mask) = nla {
+                *mask = RTEXT_FILTER_BRVLAN_COMPRESSED;
+            }
+        }
+        self
+    }
+}
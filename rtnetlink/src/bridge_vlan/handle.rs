@@ -0,0 +1,28 @@
+use super::{BridgeVlanAddRequest, BridgeVlanDelRequest, BridgeVlanGetRequest};
+use crate::Handle;
+
+pub struct BridgeVlanHandle(Handle);
+
+impl BridgeVlanHandle {
+    pub fn new(handle: Handle) -> Self {
+        BridgeVlanHandle(handle)
+    }
+
+    /// Retrieve the VLANs configured on the bridges and bridge ports (equivalent to
+    /// `bridge vlan show`)
+    pub fn get(&self) -> BridgeVlanGetRequest {
+        BridgeVlanGetRequest::new(self.0.clone())
+    }
+
+    /// Add a VLAN to the bridge port with the given index (equivalent to
+    /// `bridge vlan add dev DEV vid VID`)
+    pub fn add(&self, index: u32, vid: u16) -> BridgeVlanAddRequest {
+        BridgeVlanAddRequest::new(self.0.clone(), index, vid)
+    }
+
+    /// Remove a VLAN from the bridge port with the given index (equivalent to
+    /// `bridge vlan del dev DEV vid VID`)
+    pub fn del(&self, index: u32, vid: u16) -> BridgeVlanDelRequest {
+        BridgeVlanDelRequest::new(self.0.clone(), index, vid)
+    }
+}
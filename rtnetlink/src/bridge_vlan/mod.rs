@@ -0,0 +1,13 @@
+mod handle;
+pub use self::handle::*;
+
+mod add;
+pub use self::add::*;
+
+mod del;
+pub use self::del::*;
+
+mod get;
+pub use self::get::*;
+
+mod af_spec;
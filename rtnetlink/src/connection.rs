@@ -1,13 +1,14 @@
-use futures::channel::mpsc::UnboundedReceiver;
+use futures::channel::mpsc::{Receiver, UnboundedReceiver};
 use std::io;
 
 use crate::{
     packet::{NetlinkMessage, RtnlMessage},
-    Handle,
+    Handle, MulticastGroup, Notifications,
 };
+use log::debug;
 use netlink_proto::{
     sys::{Protocol, SocketAddr},
-    Connection,
+    Connection, OverflowPolicy,
 };
 
 #[allow(clippy::type_complexity)]
@@ -16,6 +17,57 @@ pub fn new_connection() -> io::Result<(
     Handle,
     UnboundedReceiver<(NetlinkMessage<RtnlMessage>, SocketAddr)>,
 )> {
-    let (conn, handle, messages) = netlink_proto::new_connection(Protocol::Route)?;
+    let (mut conn, handle, messages) = netlink_proto::new_connection(Protocol::Route)?;
+    enable_ext_ack(&mut conn);
+    Ok((conn, Handle::new(handle), messages))
+}
+
+/// Same as [`new_connection`](fn.new_connection.html), but the notifications are forwarded
+/// through a channel that holds at most `capacity` messages. See
+/// `netlink_proto::new_bounded_connection` for how `policy` applies when the channel is full.
+/// Requests and their responses still go through unbounded channels.
+#[allow(clippy::type_complexity)]
+pub fn new_bounded_connection(
+    capacity: usize,
+    policy: OverflowPolicy,
+) -> io::Result<(
+    Connection<RtnlMessage>,
+    Handle,
+    Receiver<(NetlinkMessage<RtnlMessage>, SocketAddr)>,
+)> {
+    let (mut conn, handle, messages) =
+        netlink_proto::new_bounded_connection(Protocol::Route, capacity, policy)?;
+    enable_ext_ack(&mut conn);
     Ok((conn, Handle::new(handle), messages))
 }
+
+/// Ask the kernel for extended ACKs, so that error messages carry a human readable explanation.
+/// Kernels older than 4.12 do not support it, in which case errors simply come without it.
+fn enable_ext_ack(conn: &mut Connection<RtnlMessage>) {
+    if let Err(e) = conn.socket_mut().set_ext_ack(true) {
+        debug!("failed to enable extended ACKs: {}", e);
+    }
+}
+
+/// Create a new connection subscribed to the given multicast groups. The notifications sent by
+/// the kernel for these groups are received on the returned stream. Unlike binding the socket
+/// with a `RTMGRP_*` bitmask, this also works for the groups that do not fit in 32 bits, like
+/// [`MulticastGroup::Nexthop`](enum.MulticastGroup.html#variant.Nexthop).
+///
+/// On a busy system, the notifications can overflow the socket receive buffer. The stream then
+/// yields a `Notification::Overrun`, and since the responses to the requests made with the
+/// returned handle may have been lost too, **all** the pending requests of the connection fail
+/// with a `-ENOBUFS` netlink error, including dumps that are unrelated to the notifications. Use
+/// a separate connection from [`new_connection`](fn.new_connection.html) for the requests that
+/// must not be affected, or retry them on `ENOBUFS`.
+pub fn new_connection_with_groups(
+    groups: &[MulticastGroup],
+) -> io::Result<(Connection<RtnlMessage>, Handle, Notifications)> {
+    let (mut conn, handle, messages) = new_connection()?;
+    let socket = conn.socket_mut();
+    socket.bind_auto()?;
+    for group in groups {
+        socket.add_membership((*group).into())?;
+    }
+    Ok((conn, handle, Notifications::new(messages)))
+}
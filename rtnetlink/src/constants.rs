@@ -13,3 +13,38 @@ pub const RTMGRP_IPV6_IFINFO: u32 = 2048;
 pub const RTMGRP_DECNET_IFADDR: u32 = 4096;
 pub const RTMGRP_DECNET_ROUTE: u32 = 16_384;
 pub const RTMGRP_IPV6_PREFIX: u32 = 131_072;
+
+pub const RTNLGRP_NONE: u32 = 0;
+pub const RTNLGRP_LINK: u32 = 1;
+pub const RTNLGRP_NOTIFY: u32 = 2;
+pub const RTNLGRP_NEIGH: u32 = 3;
+pub const RTNLGRP_TC: u32 = 4;
+pub const RTNLGRP_IPV4_IFADDR: u32 = 5;
+pub const RTNLGRP_IPV4_MROUTE: u32 = 6;
+pub const RTNLGRP_IPV4_ROUTE: u32 = 7;
+pub const RTNLGRP_IPV4_RULE: u32 = 8;
+pub const RTNLGRP_IPV6_IFADDR: u32 = 9;
+pub const RTNLGRP_IPV6_MROUTE: u32 = 10;
+pub const RTNLGRP_IPV6_ROUTE: u32 = 11;
+pub const RTNLGRP_IPV6_IFINFO: u32 = 12;
+pub const RTNLGRP_DECNET_IFADDR: u32 = 13;
+pub const RTNLGRP_NOP2: u32 = 14;
+pub const RTNLGRP_DECNET_ROUTE: u32 = 15;
+pub const RTNLGRP_DECNET_RULE: u32 = 16;
+pub const RTNLGRP_NOP4: u32 = 17;
+pub const RTNLGRP_IPV6_PREFIX: u32 = 18;
+pub const RTNLGRP_IPV6_RULE: u32 = 19;
+pub const RTNLGRP_ND_USEROPT: u32 = 20;
+pub const RTNLGRP_PHONET_IFADDR: u32 = 21;
+pub const RTNLGRP_PHONET_ROUTE: u32 = 22;
+pub const RTNLGRP_DCB: u32 = 23;
+pub const RTNLGRP_IPV4_NETCONF: u32 = 24;
+pub const RTNLGRP_IPV6_NETCONF: u32 = 25;
+pub const RTNLGRP_MDB: u32 = 26;
+pub const RTNLGRP_MPLS_ROUTE: u32 = 27;
+pub const RTNLGRP_NSID: u32 = 28;
+pub const RTNLGRP_MPLS_NETCONF: u32 = 29;
+pub const RTNLGRP_IPV4_MROUTE_R: u32 = 30;
+pub const RTNLGRP_IPV6_MROUTE_R: u32 = 31;
+pub const RTNLGRP_NEXTHOP: u32 = 32;
+pub const RTNLGRP_BRVLAN: u32 = 33;
@@ -1,4 +1,7 @@
-use std::fmt::{self, Display};
+use std::{
+    fmt::{self, Display},
+    path::PathBuf,
+};
 
 use failure::{Backtrace, Context, Fail};
 
@@ -20,12 +23,23 @@ pub enum ErrorKind {
     #[fail(display = "A netlink request failed")]
     RequestFailed,
 
+    #[fail(
+        display = "A dump was interrupted too many times because the kernel tables kept changing"
+    )]
+    DumpInterrupted,
+
     #[fail(
         display = "Received a link message (RTM_GETLINK, RTM_NEWLINK, RTM_SETLINK or RTMGETLINK) with an invalid hardware address attribute: {:?}.",
         _0
     )]
     InvalidHardwareAddress(Vec<u8>),
 
+    #[fail(display = "Failed to open a netlink connection: {}", _0)]
+    ConnectionFailed(String),
+
+    #[fail(display = "Failed to open network namespace {:?}: {}", _0, _1)]
+    InvalidNetns(PathBuf, String),
+
     #[fail(display = "Failed to parse an IP address: {:?}", _0)]
     InvalidIp(Vec<u8>),
 
@@ -2,10 +2,11 @@ use failure::{Fail, ResultExt};
 use futures::Stream;
 
 use crate::{
-    packet::{NetlinkMessage, RtnlMessage},
-    AddressHandle, Error, ErrorKind, LinkHandle, RouteHandle,
+    packet::{NetlinkMessage, NetlinkPayload, RtnlMessage, NLM_F_DUMP, NLM_F_REQUEST},
+    AddressHandle, BatchRequest, BridgeVlanHandle, Error, ErrorKind, LinkHandle, RouteHandle,
+    StatsHandle,
 };
-use netlink_proto::{sys::SocketAddr, ConnectionHandle};
+use netlink_proto::{sys::SocketAddr, ConnectionHandle, ErrorKind as ProtoErrorKind};
 
 #[derive(Clone, Debug)]
 pub struct Handle(ConnectionHandle<RtnlMessage>);
@@ -24,6 +25,51 @@ impl Handle {
             .map_err(|e| e.context(ErrorKind::RequestFailed).into())
     }
 
+    /// Send several requests at once, packed in as few datagrams as possible. A response stream
+    /// is returned for each message, in the same order.
+    pub fn request_batch(
+        &mut self,
+        messages: Vec<NetlinkMessage<RtnlMessage>>,
+    ) -> Result<Vec<impl Stream<Item = NetlinkMessage<RtnlMessage>>>, Error> {
+        self.0
+            .request_batch(messages, SocketAddr::new(0, 0))
+            .map_err(|e| e.context(ErrorKind::RequestFailed).into())
+    }
+
+    /// Dump the objects described by `message` (for instance `RtnlMessage::GetLink` to dump all
+    /// the links) and collect them. Dumps that the kernel flags as inconsistent because its
+    /// tables changed in the meantime are retried up to `max_retries` times, after which an
+    /// `ErrorKind::DumpInterrupted` error is returned.
+    pub async fn dump(
+        &mut self,
+        message: RtnlMessage,
+        max_retries: usize,
+    ) -> Result<Vec<RtnlMessage>, Error> {
+        let mut req = NetlinkMessage::from(message);
+        req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+
+        match self
+            .0
+            .dump_with_retries(req, SocketAddr::new(0, 0), max_retries)
+            .await
+        {
+            Ok(messages) => Ok(messages
+                .into_iter()
+                .filter_map(|message| match message.payload {
+                    NetlinkPayload::InnerMessage(message) => Some(message),
+                    _ => None,
+                })
+                .collect()),
+            Err(e) => match e.kind() {
+                ProtoErrorKind::DumpInterrupted => Err(ErrorKind::DumpInterrupted.into()),
+                ProtoErrorKind::KernelError(err) => {
+                    Err(ErrorKind::NetlinkError(err.clone()).into())
+                }
+                _ => Err(e.context(ErrorKind::RequestFailed).into()),
+            },
+        }
+    }
+
     pub fn notify(&mut self, msg: NetlinkMessage<RtnlMessage>) -> Result<(), Error> {
         self.0
             .notify(msg, SocketAddr::new(0, 0))
@@ -45,4 +91,21 @@ impl Handle {
     pub fn route(&self) -> RouteHandle {
         RouteHandle::new(self.clone())
     }
+
+    /// Create a new handle, specifically for bridge VLAN requests (equivalent to `bridge vlan`
+    /// commands)
+    pub fn bridge_vlan(&self) -> BridgeVlanHandle {
+        BridgeVlanHandle::new(self.clone())
+    }
+
+    /// Create a new batch of requests, that are sent to the kernel at once
+    pub fn batch(&self) -> BatchRequest {
+        BatchRequest::new(self.clone())
+    }
+
+    /// Create a new handle, specifically for link statistics requests (equivalent to
+    /// `ip stats` commands)
+    pub fn stats(&self) -> StatsHandle {
+        StatsHandle::new(self.clone())
+    }
 }
@@ -20,10 +20,29 @@ pub use crate::addr::*;
 mod route;
 pub use crate::route::*;
 
+mod bridge_vlan;
+pub use crate::bridge_vlan::*;
+
+mod stats;
+pub use crate::stats::*;
+
 mod connection;
 pub use crate::connection::*;
 
+mod multicast;
+pub use crate::multicast::*;
+
+mod watcher;
+pub use crate::watcher::*;
+
+mod netns;
+pub use crate::netns::*;
+
+mod batch;
+pub use crate::batch::*;
+
 pub mod constants;
 
 pub use netlink_packet_route as packet;
 pub use netlink_proto::sys;
+pub use netlink_proto::OverflowPolicy;
@@ -1,14 +1,20 @@
 use futures::stream::StreamExt;
+use std::net::IpAddr;
 
 use crate::{
     packet::{
-        nlas::link::{Info, InfoData, InfoKind, InfoVlan, Nla, VethInfo},
+        nlas::link::{
+            Info, InfoBridge, InfoData, InfoGeneve, InfoHsr, InfoKind, InfoVlan, InfoXfrmTun, Nla,
+            VethInfo,
+        },
         LinkMessage, NetlinkMessage, NetlinkPayload, RtnlMessage, IFF_UP, NLM_F_ACK, NLM_F_CREATE,
         NLM_F_EXCL, NLM_F_REQUEST,
     },
-    Error, ErrorKind, Handle,
+    BatchMessage, Error, ErrorKind, Handle,
 };
 
+use super::link_info::push_bridge_info;
+
 /// A request to create a new link. This is equivalent to the `ip link add` commands.
 ///
 /// A few methods for common actions (creating a veth pair, creating a vlan interface, etc.) are
@@ -33,8 +39,7 @@ impl LinkAddRequest {
             mut handle,
             message,
         } = self;
-        let mut req = NetlinkMessage::from(RtnlMessage::NewLink(message));
-        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE;
+        let req = add_link_message(message);
 
         let mut response = handle.request(req)?;
         while let Some(message) = response.next().await {
@@ -112,11 +117,65 @@ impl LinkAddRequest {
     /// Create a new bridge.
     /// This is equivalent to `ip link add link NAME type bridge`.
     pub fn bridge(self, name: String) -> Self {
-        self.name(name.clone())
-            .link_info(InfoKind::Bridge, None)
-            .append_nla(Nla::IfName(name))
+        self.name(name).link_info(InfoKind::Bridge, None)
+    }
+
+    /// Create a new wireguard interface.
+    /// This is equivalent to `ip link add NAME type wireguard`.
+    pub fn wireguard(self, name: String) -> Self {
+        self.name(name).link_info(InfoKind::Wireguard, None)
+    }
+
+    /// Create a MACsec interface on a link.
+    /// This is equivalent to `ip link add link LINK name NAME type macsec`, but instead of
+    /// specifying a link name (`LINK`), we specify a link index.
+    pub fn macsec(self, name: String, index: u32) -> Self {
+        self.name(name)
+            .link_info(InfoKind::MacSec, None)
+            .append_nla(Nla::Link(index))
     }
 
+    /// Create a geneve tunnel.
+    /// This is equivalent to `ip link add NAME type geneve id VNI remote REMOTE`.
+    pub fn geneve(self, name: String, vni: u32, remote: IpAddr) -> Self {
+        let remote = match remote {
+            IpAddr::V4(addr) => InfoGeneve::Remote(addr),
+            IpAddr::V6(addr) => InfoGeneve::Remote6(addr),
+        };
+        self.name(name).link_info(
+            InfoKind::Geneve,
+            Some(InfoData::Geneve(vec![InfoGeneve::Id(vni), remote])),
+        )
+    }
+
+    /// Create an xfrm interface.
+    /// This is equivalent to `ip link add NAME type xfrm dev LINK if_id IF_ID`, but instead of
+    /// specifying a link name (`LINK`), we specify a link index.
+    pub fn xfrm(self, name: String, index: u32, if_id: u32) -> Self {
+        self.name(name).link_info(
+            InfoKind::Xfrm,
+            Some(InfoData::Xfrm(vec![
+                InfoXfrmTun::Link(index),
+                InfoXfrmTun::IfId(if_id),
+            ])),
+        )
+    }
+
+    /// Create an HSR (High-availability Seamless Redundancy) interface.
+    /// This is equivalent to `ip link add name NAME type hsr slave1 SLAVE1 slave2 SLAVE2`, but
+    /// instead of specifying the slaves names, we specify their link indices.
+    pub fn hsr(self, name: String, slave1: u32, slave2: u32) -> Self {
+        self.name(name).link_info(
+            InfoKind::Hsr,
+            Some(InfoData::Hsr(vec![
+                InfoHsr::Slave1(slave1),
+                InfoHsr::Slave2(slave2),
+            ])),
+        )
+    }
+
+    bridge_options!();
+
     fn up(mut self) -> Self {
         self.message.header.flags = IFF_UP;
         self.message.header.change_mask = IFF_UP;
@@ -141,3 +200,15 @@ impl LinkAddRequest {
         self
     }
 }
+
+impl From<LinkAddRequest> for BatchMessage {
+    fn from(request: LinkAddRequest) -> Self {
+        BatchMessage::new(add_link_message(request.message))
+    }
+}
+
+fn add_link_message(message: LinkMessage) -> NetlinkMessage<RtnlMessage> {
+    let mut req = NetlinkMessage::from(RtnlMessage::NewLink(message));
+    req.header.flags = NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE;
+    req
+}
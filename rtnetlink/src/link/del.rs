@@ -5,7 +5,7 @@ use crate::{
         LinkMessage, NetlinkMessage, NetlinkPayload, RtnlMessage, NLM_F_ACK, NLM_F_CREATE,
         NLM_F_EXCL, NLM_F_REQUEST,
     },
-    Error, ErrorKind, Handle,
+    BatchMessage, Error, ErrorKind, Handle,
 };
 
 pub struct LinkDelRequest {
@@ -26,8 +26,7 @@ impl LinkDelRequest {
             mut handle,
             message,
         } = self;
-        let mut req = NetlinkMessage::from(RtnlMessage::DelLink(message));
-        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE;
+        let req = del_link_message(message);
 
         let mut response = handle.request(req)?;
         while let Some(message) = response.next().await {
@@ -43,3 +42,15 @@ impl LinkDelRequest {
         &mut self.message
     }
 }
+
+impl From<LinkDelRequest> for BatchMessage {
+    fn from(request: LinkDelRequest) -> Self {
+        BatchMessage::new(del_link_message(request.message))
+    }
+}
+
+fn del_link_message(message: LinkMessage) -> NetlinkMessage<RtnlMessage> {
+    let mut req = NetlinkMessage::from(RtnlMessage::DelLink(message));
+    req.header.flags = NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE;
+    req
+}
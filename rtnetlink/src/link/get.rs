@@ -6,8 +6,8 @@ use futures::{
 
 use crate::{
     packet::{
-        nlas::link::Nla, LinkMessage, NetlinkMessage, NetlinkPayload, RtnlMessage, NLM_F_DUMP,
-        NLM_F_REQUEST,
+        nlas::link::{Info, InfoKind, Nla},
+        LinkMessage, NetlinkMessage, NetlinkPayload, RtnlMessage, NLM_F_DUMP, NLM_F_REQUEST,
     },
     Error, ErrorKind, Handle,
 };
@@ -83,6 +83,42 @@ impl LinkGetRequest {
         self
     }
 
+    /// Retrieve the link that has the given alternative name. The lookup is done by the kernel
+    /// (Linux 5.5 and later), and fails with `ENODEV` if no such link exists.
+    pub fn match_alt_name(mut self, name: String) -> Self {
+        self.dump = false;
+        self.message.nlas.push(Nla::AltIfName(name));
+        self
+    }
+
+    /// Retrieve the link with the given name. Unlike
+    /// [`set_name_filter`](#method.set_name_filter), the lookup is done by the kernel, which
+    /// returns an `ENODEV` error if no such link exists.
+    pub fn match_name(mut self, name: String) -> Self {
+        self.dump = false;
+        self.message.nlas.push(Nla::IfName(name));
+        self
+    }
+
+    /// Only return the links of the given kind (equivalent to `ip link show type KIND`)
+    pub fn match_kind(mut self, kind: InfoKind) -> Self {
+        self.message
+            .nlas
+            .push(Nla::Info(vec![Info::Kind(kind.clone())]));
+        self.filter_builder.kind = Some(kind);
+        self
+    }
+
+    /// Only return the links enslaved to the link with the given index (equivalent to
+    /// `ip link show master DEV`)
+    pub fn match_master(mut self, master_index: u32) -> Self {
+        self.message.nlas.push(Nla::Master(master_index));
+        self.filter_builder.master = Some(master_index);
+        self
+    }
+
+    /// Only return the link with the given name. All the links are dumped and filtered on the
+    /// client side.
     pub fn set_name_filter(mut self, name: String) -> Self {
         self.filter_builder.name = Some(name);
         self
@@ -92,6 +128,10 @@ impl LinkGetRequest {
 #[derive(Default)]
 struct LinkFilterBuilder {
     name: Option<String>,
+    // The kind and master filters are also applied by the kernel (Linux 4.14 and later) when
+    // dumping links. We still check them here, since older kernels ignore them.
+    kind: Option<InfoKind>,
+    master: Option<u32>,
 }
 
 impl LinkFilterBuilder {
@@ -102,17 +142,36 @@ impl LinkFilterBuilder {
     fn build(self) -> impl Fn(&LinkMessage) -> bool {
         move |msg: &LinkMessage| {
             if let Some(name) = &self.name {
-                for nla in msg.nlas.iter() {
-                    if let Nla::IfName(s) = nla {
-                        if s == name {
-                            return true;
-                        }
-                    }
+                let matches = msg.nlas.iter().any(|nla| match nla {
+                    Nla::IfName(s) => s == name,
+                    _ => false,
+                });
+                if !matches {
+                    return false;
+                }
+            }
+            if let Some(kind) = &self.kind {
+                let matches = msg.nlas.iter().any(|nla| match nla {
+                    Nla::Info(infos) => infos.iter().any(|info| match info {
+                        Info::Kind(k) => k == kind,
+                        _ => false,
+                    }),
+                    _ => false,
+                });
+                if !matches {
+                    return false;
+                }
+            }
+            if let Some(master) = self.master {
+                let matches = msg.nlas.iter().any(|nla| match nla {
+                    Nla::Master(m) => *m == master,
+                    _ => false,
+                });
+                if !matches {
+                    return false;
                 }
-                false
-            } else {
-                true
             }
+            true
         }
     }
 }
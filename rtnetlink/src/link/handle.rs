@@ -1,5 +1,10 @@
-use super::{LinkAddRequest, LinkDelRequest, LinkGetRequest, LinkSetRequest};
-use crate::Handle;
+use super::{
+    LinkAddRequest, LinkDelPropRequest, LinkDelRequest, LinkGetRequest, LinkNewPropRequest,
+    LinkSetRequest,
+};
+use futures::stream::TryStreamExt;
+
+use crate::{Error, ErrorKind, Handle};
 
 pub struct LinkHandle(Handle);
 
@@ -20,8 +25,27 @@ impl LinkHandle {
         LinkDelRequest::new(self.0.clone(), index)
     }
 
+    /// Add properties, like alternative names, to a link
+    pub fn property_add(&self, index: u32) -> LinkNewPropRequest {
+        LinkNewPropRequest::new(self.0.clone(), index)
+    }
+
+    /// Remove properties, like alternative names, from a link
+    pub fn property_del(&self, index: u32) -> LinkDelPropRequest {
+        LinkDelPropRequest::new(self.0.clone(), index)
+    }
+
     /// Retrieve the list of links (equivalent to `ip link show`)
     pub fn get(&mut self) -> LinkGetRequest {
         LinkGetRequest::new(self.0.clone())
     }
+
+    /// Return the index of the link with the given name
+    pub async fn index_of(&mut self, name: String) -> Result<u32, Error> {
+        let mut links = self.get().match_name(name).execute();
+        match links.try_next().await? {
+            Some(link) => Ok(link.header.index),
+            None => Err(ErrorKind::RequestFailed.into()),
+        }
+    }
 }
@@ -0,0 +1,274 @@
+use crate::packet::{
+    nlas::link::{
+        Info, InfoBridge, InfoBridgePort, InfoData, InfoKind, InfoSlaveData, InfoSlaveKind, Nla,
+    },
+    LinkMessage,
+};
+
+/// Return the IFLA_LINKINFO attribute of the message, creating it if necessary.
+fn link_info_mut(message: &mut LinkMessage) -> &mut Vec<Info> {
+    let index = match message
+        .nlas
+        .iter()
+        .position(|nla| matches!(nla, Nla::Info(_)))
+    {
+        Some(index) => index,
+        None => {
+            message.nlas.push(Nla::Info(vec![]));
+            message.nlas.len() - 1
+        }
+    };
+    match message.nlas[index] {
+        Nla::Info(ref mut infos) => infos,
+        _ => unreachable!(),
+    }
+}
+
+/// Return the IFLA_INFO_DATA attribute of the message, creating it (as well as the corresponding
+/// IFLA_INFO_KIND attribute) if necessary. `default` is the value used to initialize the
+/// attribute when it is created. Return `None` if the message is about another kind of link.
+pub(crate) fn info_data_mut(
+    message: &mut LinkMessage,
+    kind: InfoKind,
+    default: InfoData,
+) -> Option<&mut InfoData> {
+    let infos = link_info_mut(message);
+    match infos.iter().find_map(|info| match info {
+        Info::Kind(kind) => Some(kind),
+        _ => None,
+    }) {
+        Some(existing) if *existing != kind => return None,
+        Some(_) => {}
+        // the kernel does not care, but we can only parse IFLA_INFO_DATA if it is preceded by
+        // IFLA_INFO_KIND.
+        None => infos.insert(0, Info::Kind(kind)),
+    }
+    let index = match infos.iter().position(|info| matches!(info, Info::Data(_))) {
+        Some(index) => index,
+        None => {
+            infos.push(Info::Data(default));
+            infos.len() - 1
+        }
+    };
+    match infos[index] {
+        Info::Data(ref mut data) => Some(data),
+        _ => unreachable!(),
+    }
+}
+
+/// Generate the `bridge_*` methods shared by `LinkAddRequest` and `LinkSetRequest`. The request
+/// must have a `message: LinkMessage` field.
+macro_rules! bridge_options {
+    () => {
+        /// Set the STP state of the bridge: 0 disables STP, 1 enables kernel STP and 2 enables
+        /// user space STP. This is equivalent to the `type bridge stp_state STATE` option of
+        /// `ip link`.
+        ///
+        /// Bridge options are ignored if the request already configures another kind of link.
+        pub fn bridge_stp_state(mut self, state: u32) -> Self {
+            push_bridge_info(&mut self.message, InfoBridge::StpState(state));
+            self
+        }
+
+        /// Set the forwarding delay of the bridge, in hundredths of second. This is equivalent
+        /// to the `type bridge forward_delay DELAY` option of `ip link`.
+        pub fn bridge_forward_delay(mut self, delay: u32) -> Self {
+            push_bridge_info(&mut self.message, InfoBridge::ForwardDelay(delay));
+            self
+        }
+
+        /// Set the hello time of the bridge, in hundredths of second. This is equivalent to the
+        /// `type bridge hello_time TIME` option of `ip link`.
+        pub fn bridge_hello_time(mut self, time: u32) -> Self {
+            push_bridge_info(&mut self.message, InfoBridge::HelloTime(time));
+            self
+        }
+
+        /// Set the maximum message age of the bridge, in hundredths of second. This is
+        /// equivalent to the `type bridge max_age AGE` option of `ip link`.
+        pub fn bridge_max_age(mut self, age: u32) -> Self {
+            push_bridge_info(&mut self.message, InfoBridge::MaxAge(age));
+            self
+        }
+
+        /// Set the ageing time of the bridge FDB entries, in hundredths of second. This is
+        /// equivalent to the `type bridge ageing_time TIME` option of `ip link`.
+        pub fn bridge_ageing_time(mut self, time: u32) -> Self {
+            push_bridge_info(&mut self.message, InfoBridge::AgeingTime(time));
+            self
+        }
+
+        /// Enable or disable VLAN filtering on the bridge. This is equivalent to the
+        /// `type bridge vlan_filtering { 0 | 1 }` option of `ip link`.
+        pub fn bridge_vlan_filtering(mut self, enabled: bool) -> Self {
+            push_bridge_info(&mut self.message, InfoBridge::VlanFiltering(enabled as u8));
+            self
+        }
+
+        /// Set the default PVID of the bridge ports. This is equivalent to the
+        /// `type bridge vlan_default_pvid PVID` option of `ip link`.
+        pub fn bridge_vlan_default_pvid(mut self, pvid: u16) -> Self {
+            push_bridge_info(&mut self.message, InfoBridge::VlanDefaultPvid(pvid));
+            self
+        }
+
+        /// Set the VLAN protocol used for filtering (`ETH_P_8021Q` or `ETH_P_8021AD`). This is
+        /// equivalent to the `type bridge vlan_protocol PROTOCOL` option of `ip link`.
+        pub fn bridge_vlan_protocol(mut self, protocol: u16) -> Self {
+            push_bridge_info(&mut self.message, InfoBridge::VlanProtocol(protocol));
+            self
+        }
+
+        /// Set the group forward mask of the bridge. This is equivalent to the
+        /// `type bridge group_fwd_mask MASK` option of `ip link`.
+        pub fn bridge_group_fwd_mask(mut self, mask: u16) -> Self {
+            push_bridge_info(&mut self.message, InfoBridge::GroupFwdMask(mask));
+            self
+        }
+
+        /// Enable or disable multicast snooping on the bridge. This is equivalent to the
+        /// `type bridge mcast_snooping { 0 | 1 }` option of `ip link`.
+        pub fn bridge_mcast_snooping(mut self, enabled: bool) -> Self {
+            push_bridge_info(
+                &mut self.message,
+                InfoBridge::MulticastSnooping(enabled as u8),
+            );
+            self
+        }
+
+        /// Enable or disable the multicast querier of the bridge. This is equivalent to the
+        /// `type bridge mcast_querier { 0 | 1 }` option of `ip link`.
+        pub fn bridge_mcast_querier(mut self, enabled: bool) -> Self {
+            push_bridge_info(
+                &mut self.message,
+                InfoBridge::MulticastQuerier(enabled as u8),
+            );
+            self
+        }
+
+        /// Use the bridge IP address as source address for the multicast queries. This is
+        /// equivalent to the `type bridge mcast_query_use_ifaddr { 0 | 1 }` option of `ip link`.
+        pub fn bridge_mcast_query_use_ifaddr(mut self, enabled: bool) -> Self {
+            push_bridge_info(
+                &mut self.message,
+                InfoBridge::MulticastQueryUseIfaddr(enabled as u8),
+            );
+            self
+        }
+
+        /// Set the multicast querier interval, in hundredths of second. This is equivalent to
+        /// the `type bridge mcast_querier_interval INTERVAL` option of `ip link`.
+        pub fn bridge_mcast_querier_interval(mut self, interval: u64) -> Self {
+            push_bridge_info(
+                &mut self.message,
+                InfoBridge::MulticastQuerierInterval(interval),
+            );
+            self
+        }
+
+        /// Set the multicast query interval, in hundredths of second. This is equivalent to the
+        /// `type bridge mcast_query_interval INTERVAL` option of `ip link`.
+        pub fn bridge_mcast_query_interval(mut self, interval: u64) -> Self {
+            push_bridge_info(
+                &mut self.message,
+                InfoBridge::MulticastQueryInterval(interval),
+            );
+            self
+        }
+
+        /// Set the multicast query response interval, in hundredths of second. This is
+        /// equivalent to the `type bridge mcast_query_response_interval INTERVAL` option of
+        /// `ip link`.
+        pub fn bridge_mcast_query_response_interval(mut self, interval: u64) -> Self {
+            push_bridge_info(
+                &mut self.message,
+                InfoBridge::MulticastQueryResponseInterval(interval),
+            );
+            self
+        }
+    };
+}
+
+/// Append a bridge attribute to the IFLA_INFO_DATA of the message.
+///
+/// If the message is about another kind of link (for instance because it is a request to create
+/// a veth pair), the attribute is not added: bridge options only make sense for bridges, and
+/// mixing them with the options of another link type would produce a message the kernel rejects.
+pub(crate) fn push_bridge_info(message: &mut LinkMessage, nla: InfoBridge) {
+    let data = info_data_mut(message, InfoKind::Bridge, InfoData::Bridge(vec![]));
+    if let Some(InfoData::Bridge(nlas)) = data {
+        nlas.push(nla);
+    }
+}
+
+/// Return the IFLA_INFO_SLAVE_DATA attribute of the message, creating it (as well as the
+/// corresponding IFLA_INFO_SLAVE_KIND attribute) if necessary.
+fn slave_data_mut(
+    message: &mut LinkMessage,
+    kind: InfoSlaveKind,
+    default: InfoSlaveData,
+) -> &mut InfoSlaveData {
+    let infos = link_info_mut(message);
+    if !infos.iter().any(|info| matches!(info, Info::SlaveKind(_))) {
+        infos.push(Info::SlaveKind(kind));
+    }
+    let index = match infos
+        .iter()
+        .position(|info| matches!(info, Info::SlaveData(_)))
+    {
+        Some(index) => index,
+        None => {
+            infos.push(Info::SlaveData(default));
+            infos.len() - 1
+        }
+    };
+    match infos[index] {
+        Info::SlaveData(ref mut data) => data,
+        _ => unreachable!(),
+    }
+}
+
+/// Append a bridge port attribute to the IFLA_INFO_SLAVE_DATA of the message.
+pub(crate) fn push_bridge_port_info(message: &mut LinkMessage, nla: InfoBridgePort) {
+    let data = slave_data_mut(
+        message,
+        InfoSlaveKind::Bridge,
+        InfoSlaveData::Bridge(vec![]),
+    );
+    if let InfoSlaveData::Bridge(ref mut nlas) = data {
+        nlas.push(nla);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn push_bridge_info_to_bridge() {
+        let mut message = LinkMessage::default();
+        message
+            .nlas
+            .push(Nla::Info(vec![Info::Kind(InfoKind::Bridge)]));
+        push_bridge_info(&mut message, InfoBridge::StpState(1));
+        assert_eq!(
+            message.nlas,
+            vec![Nla::Info(vec![
+                Info::Kind(InfoKind::Bridge),
+                Info::Data(InfoData::Bridge(vec![InfoBridge::StpState(1)])),
+            ])]
+        );
+    }
+
+    #[test]
+    fn push_bridge_info_to_other_kind() {
+        // a link of another kind, without IFLA_INFO_DATA yet
+        let mut message = LinkMessage::default();
+        message
+            .nlas
+            .push(Nla::Info(vec![Info::Kind(InfoKind::Wireguard)]));
+        let expected = message.clone();
+        push_bridge_info(&mut message, InfoBridge::StpState(1));
+        assert_eq!(message, expected);
+    }
+}
@@ -1,3 +1,6 @@
+#[macro_use]
+mod link_info;
+
 mod handle;
 pub use self::handle::*;
 
@@ -12,3 +15,9 @@ pub use self::get::*;
 
 mod set;
 pub use self::set::*;
+
+mod property_add;
+pub use self::property_add::*;
+
+mod property_del;
+pub use self::property_del::*;
@@ -0,0 +1,61 @@
+use futures::stream::StreamExt;
+
+use crate::{
+    packet::{
+        nlas::link::{Nla, Prop},
+        LinkMessage, NetlinkMessage, NetlinkPayload, RtnlMessage, NLM_F_ACK, NLM_F_APPEND,
+        NLM_F_EXCL, NLM_F_REQUEST,
+    },
+    Error, ErrorKind, Handle,
+};
+
+pub struct LinkNewPropRequest {
+    handle: Handle,
+    message: LinkMessage,
+}
+
+impl LinkNewPropRequest {
+    pub(crate) fn new(handle: Handle, index: u32) -> Self {
+        let mut message = LinkMessage::default();
+        message.header.index = index;
+        LinkNewPropRequest { handle, message }
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let LinkNewPropRequest {
+            mut handle,
+            message,
+        } = self;
+        let mut req = NetlinkMessage::from(RtnlMessage::NewLinkProp(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_APPEND;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            if let NetlinkPayload::Error(err) = message.payload {
+                return Err(ErrorKind::NetlinkError(err).into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut LinkMessage {
+        &mut self.message
+    }
+
+    /// Add an alternative name to the link (equivalent to
+    /// `ip link property add dev DEV altname NAME`). This method can be called multiple times to
+    /// add several names at once.
+    pub fn alt_ifname(mut self, name: String) -> Self {
+        let prop = Prop::AltIfName(name);
+        for nla in self.message.nlas.iter_mut() {
+            if let Nla::PropList(ref mut props) = nla {
+                props.push(prop);
+                return self;
+            }
+        }
+        self.message.nlas.push(Nla::PropList(vec![prop]));
+        self
+    }
+}
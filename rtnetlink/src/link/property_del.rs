@@ -0,0 +1,60 @@
+use futures::stream::StreamExt;
+
+use crate::{
+    packet::{
+        nlas::link::{Nla, Prop},
+        LinkMessage, NetlinkMessage, NetlinkPayload, RtnlMessage, NLM_F_ACK, NLM_F_REQUEST,
+    },
+    Error, ErrorKind, Handle,
+};
+
+pub struct LinkDelPropRequest {
+    handle: Handle,
+    message: LinkMessage,
+}
+
+impl LinkDelPropRequest {
+    pub(crate) fn new(handle: Handle, index: u32) -> Self {
+        let mut message = LinkMessage::default();
+        message.header.index = index;
+        LinkDelPropRequest { handle, message }
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let LinkDelPropRequest {
+            mut handle,
+            message,
+        } = self;
+        let mut req = NetlinkMessage::from(RtnlMessage::DelLinkProp(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            if let NetlinkPayload::Error(err) = message.payload {
+                return Err(ErrorKind::NetlinkError(err).into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut LinkMessage {
+        &mut self.message
+    }
+
+    /// Remove an alternative name from the link (equivalent to
+    /// `ip link property del dev DEV altname NAME`). This method can be called multiple times to
+    /// remove several names at once.
+    pub fn alt_ifname(mut self, name: String) -> Self {
+        let prop = Prop::AltIfName(name);
+        for nla in self.message.nlas.iter_mut() {
+            if let Nla::PropList(ref mut props) = nla {
+                props.push(prop);
+                return self;
+            }
+        }
+        self.message.nlas.push(Nla::PropList(vec![prop]));
+        self
+    }
+}
@@ -1,33 +1,50 @@
 use crate::{
+    netns_path,
     packet::{
-        nlas::link::Nla, LinkMessage, NetlinkMessage, NetlinkPayload, RtnlMessage, IFF_UP,
-        NLM_F_ACK, NLM_F_CREATE, NLM_F_EXCL, NLM_F_REQUEST,
+        nlas::link::{
+            AfSpecInet, Inet, Inet6, InetDevConfValue, InfoBridge, InfoBridgePort, Nla, State, Vf,
+            VfInfo, VfMac, VfSetting, VfVlan, Xdp,
+        },
+        LinkMessage, NetlinkMessage, NetlinkPayload, RtnlMessage, IFF_ALLMULTI, IFF_NOARP,
+        IFF_PROMISC, IFF_UP, IPV4_DEVCONF_FORWARDING, IPV4_DEVCONF_PROXY_ARP,
+        IPV4_DEVCONF_RP_FILTER, NLM_F_ACK, NLM_F_CREATE, NLM_F_EXCL, NLM_F_REQUEST,
+        XDP_FLAGS_DRV_MODE, XDP_FLAGS_HW_MODE, XDP_FLAGS_REPLACE, XDP_FLAGS_SKB_MODE,
     },
-    Error, ErrorKind, Handle,
+    BatchMessage, Error, ErrorKind, Handle,
 };
 use futures::stream::StreamExt;
-use std::os::unix::io::RawFd;
+use std::{
+    fs::File,
+    net::Ipv6Addr,
+    os::unix::io::{AsRawFd, RawFd},
+    path::{Path, PathBuf},
+};
+
+use super::link_info::{push_bridge_info, push_bridge_port_info};
 
 pub struct LinkSetRequest {
     handle: Handle,
     message: LinkMessage,
+    // Path of the network namespace to move the link to. The namespace file is only opened when
+    // the request is executed, and must stay open until the kernel has processed the request.
+    netns_path: Option<PathBuf>,
 }
 
 impl LinkSetRequest {
     pub(crate) fn new(handle: Handle, index: u32) -> Self {
         let mut message = LinkMessage::default();
         message.header.index = index;
-        LinkSetRequest { handle, message }
+        LinkSetRequest {
+            handle,
+            message,
+            netns_path: None,
+        }
     }
 
     /// Execute the request
     pub async fn execute(self) -> Result<(), Error> {
-        let LinkSetRequest {
-            mut handle,
-            message,
-        } = self;
-        let mut req = NetlinkMessage::from(RtnlMessage::SetLink(message));
-        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE;
+        let mut handle = self.handle.clone();
+        let (req, _netns) = self.into_message()?;
 
         let mut response = handle.request(req)?;
         while let Some(message) = response.next().await {
@@ -38,6 +55,27 @@ impl LinkSetRequest {
         Ok(())
     }
 
+    /// Build the netlink message of the request. If the link is moved to another network
+    /// namespace, the namespace file is returned too: it must stay open until the kernel has
+    /// processed the request.
+    fn into_message(self) -> Result<(NetlinkMessage<RtnlMessage>, Option<File>), ErrorKind> {
+        let LinkSetRequest {
+            mut message,
+            netns_path,
+            ..
+        } = self;
+        let netns = match netns_path {
+            Some(path) => {
+                let file = File::open(&path)
+                    .map_err(|e| ErrorKind::InvalidNetns(path.clone(), e.to_string()))?;
+                message.nlas.push(Nla::NetNsFd(file.as_raw_fd()));
+                Some(file)
+            }
+            None => None,
+        };
+        Ok((set_link_message(message), netns))
+    }
+
     /// Return a mutable reference to the request
     pub fn message_mut(&mut self) -> &mut LinkMessage {
         &mut self.message
@@ -51,16 +89,36 @@ impl LinkSetRequest {
     }
 
     /// Set the link with the given index up (equivalent to `ip link set dev DEV up`)
-    pub fn up(mut self) -> Self {
-        self.message.header.flags = IFF_UP;
-        self.message.header.change_mask = IFF_UP;
-        self
+    pub fn up(self) -> Self {
+        self.set_flag(IFF_UP, true)
     }
 
     /// Set the link with the given index down (equivalent to `ip link set dev DEV down`)
-    pub fn down(mut self) -> Self {
-        self.message.header.change_mask = IFF_UP;
-        self
+    pub fn down(self) -> Self {
+        self.set_flag(IFF_UP, false)
+    }
+
+    /// Enable or disable the promiscuous mode of the link (equivalent to
+    /// `ip link set DEV promisc { on | off }`)
+    pub fn promiscuous(self, enable: bool) -> Self {
+        self.set_flag(IFF_PROMISC, enable)
+    }
+
+    /// Enable or disable the reception of all multicast packets on the link (equivalent to
+    /// `ip link set DEV allmulticast { on | off }`)
+    pub fn allmulticast(self, enable: bool) -> Self {
+        self.set_flag(IFF_ALLMULTI, enable)
+    }
+
+    /// Enable or disable the ARP protocol on the link (equivalent to
+    /// `ip link set DEV arp { on | off }`)
+    pub fn arp(self, enable: bool) -> Self {
+        self.set_flag(IFF_NOARP, !enable)
+    }
+
+    /// Detach the link from its master device (equivalent to `ip link set LINK nomaster`)
+    pub fn nomaster(self) -> Self {
+        self.master(0)
     }
 
     /// Set the name of the link with the given index (equivalent to `ip link set DEV name NAME`)
@@ -81,6 +139,76 @@ impl LinkSetRequest {
         self
     }
 
+    /// Set the transmit queue length of the link (equivalent to `ip link set DEV txqueuelen LEN`)
+    pub fn txqueuelen(mut self, len: u32) -> Self {
+        self.message.nlas.push(Nla::TxQueueLen(len));
+        self
+    }
+
+    /// Set the alias of the link (equivalent to `ip link set DEV alias ALIAS`). An empty alias
+    /// removes the current one.
+    pub fn alias(mut self, alias: String) -> Self {
+        self.message.nlas.push(Nla::IfAlias(alias));
+        self
+    }
+
+    /// Set the group the link belongs to (equivalent to `ip link set DEV group GROUP`)
+    pub fn group(mut self, group: u32) -> Self {
+        self.message.nlas.push(Nla::Group(group));
+        self
+    }
+
+    /// Set the maximum size of the GSO packets the link accepts (equivalent to
+    /// `ip link set DEV gso_max_size SIZE`)
+    pub fn gso_max_size(mut self, size: u32) -> Self {
+        self.message.nlas.push(Nla::GsoMaxSize(size));
+        self
+    }
+
+    /// Set the maximum number of segments of the GSO packets the link accepts (equivalent to
+    /// `ip link set DEV gso_max_segs SEGMENTS`)
+    pub fn gso_max_segs(mut self, segments: u32) -> Self {
+        self.message.nlas.push(Nla::GsoMaxSegs(segments));
+        self
+    }
+
+    /// Set or clear the protocol error state of the link (equivalent to
+    /// `ip link set DEV protodown { on | off }`)
+    pub fn protodown(mut self, enable: bool) -> Self {
+        self.message.nlas.push(Nla::ProtoDown(enable as u8));
+        self
+    }
+
+    /// Set the `IFLA_QDISC` attribute of the link. Note that most kernels only report this
+    /// attribute and ignore it in requests: the root qdisc is normally changed with the traffic
+    /// control API.
+    pub fn qdisc(mut self, qdisc: String) -> Self {
+        self.message.nlas.push(Nla::Qdisc(qdisc));
+        self
+    }
+
+    /// Set the carrier state of the link. This is only supported by some virtual devices, like
+    /// `dummy` links.
+    pub fn carrier(mut self, carrier: bool) -> Self {
+        self.message.nlas.push(Nla::Carrier(carrier as u8));
+        self
+    }
+
+    /// Set the link mode, one of the `IF_LINK_MODE_*` constants. With `IF_LINK_MODE_DORMANT`,
+    /// the link stays dormant until user space sets its operational state to
+    /// `State::Up`.
+    pub fn link_mode(mut self, mode: u8) -> Self {
+        self.message.nlas.push(Nla::Mode(mode));
+        self
+    }
+
+    /// Set the operational state of the link (RFC 2863). This is mostly useful for links in
+    /// dormant mode (see [`link_mode`](#method.link_mode)).
+    pub fn oper_state(mut self, state: State) -> Self {
+        self.message.nlas.push(Nla::OperState(state));
+        self
+    }
+
     /// Move this network device into the network namespace of the process with the given `pid`.
     pub fn setns_by_pid(mut self, pid: u32) -> Self {
         self.message.nlas.push(Nla::NetNsPid(pid));
@@ -93,4 +221,318 @@ impl LinkSetRequest {
         self.message.nlas.push(Nla::NetNsFd(fd));
         self
     }
+
+    /// Move this network device into the network namespace at the given path, for instance
+    /// `/proc/1/ns/net` or a namespace bind mounted by `ip netns add`.
+    pub fn setns_by_path<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.netns_path = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Move this network device into the named network namespace (equivalent to
+    /// `ip link set DEV netns NAME`).
+    pub fn setns_by_name(self, name: &str) -> Self {
+        self.setns_by_path(netns_path(name))
+    }
+
+    /// Set an IPv4 configuration value of the link, without writing to
+    /// `/proc/sys/net/ipv4/conf/DEV`. `index` is one of the `IPV4_DEVCONF_*` constants.
+    pub fn ipv4_devconf(mut self, index: u16, value: u32) -> Self {
+        self.inet_devconf_mut()
+            .push(InetDevConfValue { index, value });
+        self
+    }
+
+    /// Enable or disable IPv4 forwarding on the link (equivalent to
+    /// `sysctl net.ipv4.conf.DEV.forwarding`)
+    pub fn ipv4_forwarding(self, enable: bool) -> Self {
+        self.ipv4_devconf(IPV4_DEVCONF_FORWARDING, enable as u32)
+    }
+
+    /// Set the reverse path filtering mode of the link: 0 disables it, 1 enables strict mode and
+    /// 2 loose mode (equivalent to `sysctl net.ipv4.conf.DEV.rp_filter`)
+    pub fn ipv4_rp_filter(self, mode: u32) -> Self {
+        self.ipv4_devconf(IPV4_DEVCONF_RP_FILTER, mode)
+    }
+
+    /// Enable or disable proxy ARP on the link (equivalent to
+    /// `sysctl net.ipv4.conf.DEV.proxy_arp`)
+    pub fn ipv4_proxy_arp(self, enable: bool) -> Self {
+        self.ipv4_devconf(IPV4_DEVCONF_PROXY_ARP, enable as u32)
+    }
+
+    /// Set the IPv6 token of the link, used with stateless address autoconfiguration to
+    /// generate the interface identifier of the addresses (equivalent to
+    /// `ip token set TOKEN dev DEV`).
+    pub fn ipv6_token(mut self, token: Ipv6Addr) -> Self {
+        self.af_spec_inet6_mut().push(Inet6::Token(token.octets()));
+        self
+    }
+
+    /// Set how the IPv6 link local and autoconfigured addresses of the link are generated. `mode`
+    /// is one of the `IN6_ADDR_GEN_MODE_*` constants. This is equivalent to
+    /// `ip link set DEV addrgenmode { eui64 | none | stable_secret | random }`.
+    pub fn ipv6_addr_gen_mode(mut self, mode: u8) -> Self {
+        self.af_spec_inet6_mut().push(Inet6::AddrGenMode(mode));
+        self
+    }
+
+    /// Attach the XDP program referred to by the file descriptor `fd` to this link, letting the
+    /// kernel pick the attach mode. This is equivalent to `ip link set DEV xdp fd FD`.
+    pub fn xdp_attach(mut self, fd: RawFd) -> Self {
+        self.push_xdp_nla(Xdp::Fd(fd));
+        self
+    }
+
+    /// Attach the XDP program referred to by the file descriptor `fd` to this link in generic
+    /// (skb) mode. This is equivalent to `ip link set DEV xdpgeneric fd FD`.
+    pub fn xdp_attach_skb(self, fd: RawFd) -> Self {
+        self.xdp_attach(fd).xdp_flags(XDP_FLAGS_SKB_MODE)
+    }
+
+    /// Attach the XDP program referred to by the file descriptor `fd` to this link in native
+    /// (driver) mode. This is equivalent to `ip link set DEV xdpdrv fd FD`.
+    pub fn xdp_attach_drv(self, fd: RawFd) -> Self {
+        self.xdp_attach(fd).xdp_flags(XDP_FLAGS_DRV_MODE)
+    }
+
+    /// Attach the XDP program referred to by the file descriptor `fd` to this link in hardware
+    /// offload mode. This is equivalent to `ip link set DEV xdpoffload fd FD`.
+    pub fn xdp_attach_hw(self, fd: RawFd) -> Self {
+        self.xdp_attach(fd).xdp_flags(XDP_FLAGS_HW_MODE)
+    }
+
+    /// Detach the XDP program currently attached to this link. This is equivalent to
+    /// `ip link set DEV xdp off`. If the program was attached in a specific mode, the same mode
+    /// must be passed with [`xdp_flags`](#method.xdp_flags).
+    pub fn xdp_detach(self) -> Self {
+        self.xdp_attach(-1)
+    }
+
+    /// Add the given `XDP_FLAGS_*` flags to the XDP request, for instance
+    /// `XDP_FLAGS_UPDATE_IF_NOEXIST` to fail if a program is already attached.
+    pub fn xdp_flags(mut self, flags: u32) -> Self {
+        for nla in self.xdp_nlas_mut().iter_mut() {
+            if let Xdp::Flags(ref mut value) = nla {
+                *value |= flags;
+                return self;
+            }
+        }
+        self.push_xdp_nla(Xdp::Flags(flags));
+        self
+    }
+
+    /// Only replace the XDP program attached to this link if it is the one referred to by the file
+    /// descriptor `fd`.
+    pub fn xdp_expected_fd(mut self, fd: RawFd) -> Self {
+        self.push_xdp_nla(Xdp::ExpectedFd(fd));
+        self.xdp_flags(XDP_FLAGS_REPLACE)
+    }
+
+    /// Set the MAC address of the virtual function `vf` of this SR-IOV device. This is equivalent
+    /// to `ip link set DEV vf VF mac ADDRESS`.
+    pub fn vf_mac(mut self, vf: u32, address: [u8; 6]) -> Self {
+        self.push_vf_nla(Vf::Mac(VfMac::new(vf, address)));
+        self
+    }
+
+    /// Set the VLAN ID and the 802.1p priority of the traffic of the virtual function `vf`. A VLAN
+    /// ID of 0 disables VLAN tagging. This is equivalent to
+    /// `ip link set DEV vf VF vlan VLAN qos QOS`.
+    pub fn vf_vlan(mut self, vf: u32, vlan: u32, qos: u32) -> Self {
+        self.push_vf_nla(Vf::Vlan(VfVlan { vf, vlan, qos }));
+        self
+    }
+
+    /// Mark the virtual function `vf` as trusted or not. This is equivalent to
+    /// `ip link set DEV vf VF trust { on | off }`.
+    pub fn vf_trust(mut self, vf: u32, trusted: bool) -> Self {
+        self.push_vf_nla(Vf::Trust(VfSetting {
+            vf,
+            setting: trusted as u32,
+        }));
+        self
+    }
+
+    bridge_options!();
+
+    /// Set a bridge port attribute on this link, which must be enslaved to a bridge. For
+    /// instance, `bridge_port(InfoBridgePort::Learning(0))` is equivalent to
+    /// `ip link set DEV type bridge_slave learning off`.
+    ///
+    /// Like the bridge options, bridge port attributes are sent in a RTM_NEWLINK message, which
+    /// the kernel forwards to the bridge the link is enslaved to.
+    pub fn bridge_port(mut self, nla: InfoBridgePort) -> Self {
+        push_bridge_port_info(&mut self.message, nla);
+        self
+    }
+
+    fn set_flag(mut self, flag: u32, enable: bool) -> Self {
+        if enable {
+            self.message.header.flags |= flag;
+        } else {
+            self.message.header.flags &= !flag;
+        }
+        self.message.header.change_mask |= flag;
+        self
+    }
+
+    // The kernel applies each IFLA_VF_INFO entry separately, so every setting gets its own entry
+    fn push_vf_nla(&mut self, nla: Vf) {
+        let info = VfInfo::Info(vec![nla]);
+        for nla in self.message.nlas.iter_mut() {
+            if let Nla::VfInfoList(ref mut infos) = nla {
+                infos.push(info);
+                return;
+            }
+        }
+        self.message.nlas.push(Nla::VfInfoList(vec![info]));
+    }
+
+    fn af_spec_inet_mut(&mut self) -> &mut Vec<AfSpecInet> {
+        let nlas = &mut self.message.nlas;
+        let index = match nlas
+            .iter()
+            .position(|nla| matches!(nla, Nla::AfSpecInet(_)))
+        {
+            Some(index) => index,
+            None => {
+                nlas.push(Nla::AfSpecInet(vec![]));
+                nlas.len() - 1
+            }
+        };
+        match nlas[index] {
+            Nla::AfSpecInet(ref mut af_spec) => af_spec,
+            _ => unreachable!(),
+        }
+    }
+
+    fn inet_devconf_mut(&mut self) -> &mut Vec<InetDevConfValue> {
+        let af_spec = self.af_spec_inet_mut();
+        let index = match af_spec
+            .iter()
+            .position(|nla| matches!(nla, AfSpecInet::Inet(_)))
+        {
+            Some(index) => index,
+            None => {
+                af_spec.push(AfSpecInet::Inet(vec![]));
+                af_spec.len() - 1
+            }
+        };
+        let inet = match af_spec[index] {
+            AfSpecInet::Inet(ref mut nlas) => nlas,
+            _ => unreachable!(),
+        };
+        let index = match inet
+            .iter()
+            .position(|nla| matches!(nla, Inet::DevConfValues(_)))
+        {
+            Some(index) => index,
+            None => {
+                inet.push(Inet::DevConfValues(vec![]));
+                inet.len() - 1
+            }
+        };
+        match inet[index] {
+            Inet::DevConfValues(ref mut values) => values,
+            _ => unreachable!(),
+        }
+    }
+
+    fn af_spec_inet6_mut(&mut self) -> &mut Vec<Inet6> {
+        let af_spec = self.af_spec_inet_mut();
+        let index = match af_spec
+            .iter()
+            .position(|nla| matches!(nla, AfSpecInet::Inet6(_)))
+        {
+            Some(index) => index,
+            None => {
+                af_spec.push(AfSpecInet::Inet6(vec![]));
+                af_spec.len() - 1
+            }
+        };
+        match af_spec[index] {
+            AfSpecInet::Inet6(ref mut nlas) => nlas,
+            _ => unreachable!(),
+        }
+    }
+
+    fn xdp_nlas_mut(&mut self) -> &mut Vec<Xdp> {
+        let nlas = &mut self.message.nlas;
+        let index = match nlas.iter().position(|nla| matches!(nla, Nla::Xdp(_))) {
+            Some(index) => index,
+            None => {
+                nlas.push(Nla::Xdp(vec![]));
+                nlas.len() - 1
+            }
+        };
+        match nlas[index] {
+            Nla::Xdp(ref mut xdp) => xdp,
+            _ => unreachable!(),
+        }
+    }
+
+    fn push_xdp_nla(&mut self, nla: Xdp) {
+        self.xdp_nlas_mut().push(nla);
+    }
+}
+
+impl From<LinkSetRequest> for BatchMessage {
+    fn from(request: LinkSetRequest) -> Self {
+        match request.into_message() {
+            Ok((message, netns)) => BatchMessage {
+                message: Ok(message),
+                netns,
+            },
+            Err(error) => BatchMessage {
+                message: Err(error),
+                netns: None,
+            },
+        }
+    }
+}
+
+/// Build the netlink message for a link set request.
+///
+/// `do_setlink()` ignores IFLA_LINKINFO, so the link type specific attributes (IFLA_INFO_DATA
+/// and IFLA_INFO_SLAVE_DATA) are only applied when they are sent in a RTM_NEWLINK message. In
+/// that case the message is sent without NLM_F_CREATE and NLM_F_EXCL, so that the kernel
+/// modifies the existing link instead of creating a new one.
+fn set_link_message(message: LinkMessage) -> NetlinkMessage<RtnlMessage> {
+    if message.nlas.iter().any(|nla| matches!(nla, Nla::Info(_))) {
+        let mut req = NetlinkMessage::from(RtnlMessage::NewLink(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+        req
+    } else {
+        let mut req = NetlinkMessage::from(RtnlMessage::SetLink(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE;
+        req
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::packet::{RTM_NEWLINK, RTM_SETLINK};
+
+    #[test]
+    fn set_link_message_type() {
+        let mut message = LinkMessage::default();
+        message.header.index = 3;
+        message.nlas.push(Nla::Mtu(1400));
+        let req = set_link_message(message.clone());
+        assert_eq!(req.payload.message_type(), RTM_SETLINK);
+
+        push_bridge_info(&mut message, InfoBridge::StpState(1));
+        let req = set_link_message(message);
+        assert_eq!(req.payload.message_type(), RTM_NEWLINK);
+        assert_eq!(req.header.flags, NLM_F_REQUEST | NLM_F_ACK);
+
+        let mut message = LinkMessage::default();
+        message.header.index = 3;
+        push_bridge_port_info(&mut message, InfoBridgePort::Learning(0));
+        let req = set_link_message(message);
+        assert_eq!(req.payload.message_type(), RTM_NEWLINK);
+        assert_eq!(req.header.flags, NLM_F_REQUEST | NLM_F_ACK);
+    }
 }
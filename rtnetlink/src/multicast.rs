@@ -0,0 +1,132 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{
+    channel::mpsc::UnboundedReceiver,
+    stream::{Stream, StreamExt},
+};
+
+use crate::{
+    constants::*,
+    packet::{NetlinkMessage, NetlinkPayload, RtnlMessage},
+    sys::SocketAddr,
+};
+
+/// Multicast groups of the route netlink protocol. A connection subscribed to a group receives
+/// a notification each time a resource of that group is created, changed or removed.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum MulticastGroup {
+    /// Link changes (`RTM_NEWLINK` and `RTM_DELLINK`)
+    Link,
+    /// Neighbour (ARP and NDP) table changes
+    Neigh,
+    /// Traffic control changes: qdiscs, classes and filters
+    Tc,
+    /// IPv4 address changes
+    Ipv4Ifaddr,
+    /// IPv4 route changes
+    Ipv4Route,
+    /// IPv4 routing rule changes
+    Ipv4Rule,
+    /// IPv4 per-interface configuration changes
+    Ipv4Netconf,
+    /// IPv6 address changes
+    Ipv6Ifaddr,
+    /// IPv6 route changes
+    Ipv6Route,
+    /// IPv6 routing rule changes
+    Ipv6Rule,
+    /// IPv6 per-interface information changes
+    Ipv6Ifinfo,
+    /// IPv6 prefixes learned from router advertisements
+    Ipv6Prefix,
+    /// IPv6 per-interface configuration changes
+    Ipv6Netconf,
+    /// Network namespace identifier changes
+    Nsid,
+    /// MPLS route changes
+    MplsRoute,
+    /// Nexthop object changes
+    Nexthop,
+    /// Bridge multicast database changes
+    Mdb,
+    /// Bridge VLAN changes
+    BridgeVlan,
+    /// Any other group, identified by its `RTNLGRP_*` number
+    Other(u32),
+}
+
+impl From<MulticastGroup> for u32 {
+    fn from(group: MulticastGroup) -> Self {
+        use self::MulticastGroup::*;
+        match group {
+            Link => RTNLGRP_LINK,
+            Neigh => RTNLGRP_NEIGH,
+            Tc => RTNLGRP_TC,
+            Ipv4Ifaddr => RTNLGRP_IPV4_IFADDR,
+            Ipv4Route => RTNLGRP_IPV4_ROUTE,
+            Ipv4Rule => RTNLGRP_IPV4_RULE,
+            Ipv4Netconf => RTNLGRP_IPV4_NETCONF,
+            Ipv6Ifaddr => RTNLGRP_IPV6_IFADDR,
+            Ipv6Route => RTNLGRP_IPV6_ROUTE,
+            Ipv6Rule => RTNLGRP_IPV6_RULE,
+            Ipv6Ifinfo => RTNLGRP_IPV6_IFINFO,
+            Ipv6Prefix => RTNLGRP_IPV6_PREFIX,
+            Ipv6Netconf => RTNLGRP_IPV6_NETCONF,
+            Nsid => RTNLGRP_NSID,
+            MplsRoute => RTNLGRP_MPLS_ROUTE,
+            Nexthop => RTNLGRP_NEXTHOP,
+            Mdb => RTNLGRP_MDB,
+            BridgeVlan => RTNLGRP_BRVLAN,
+            Other(group) => group,
+        }
+    }
+}
+
+/// A message received on a connection subscribed to multicast groups
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Notification {
+    /// A notification sent by the kernel, for instance `RtnlMessage::NewLink` when a link is
+    /// created or changed.
+    Message(RtnlMessage),
+    /// The socket receive buffer overflowed and the kernel dropped notifications. Any state built
+    /// from previous notifications may be stale, and should be dumped again.
+    Overrun,
+}
+
+/// The stream of [`Notification`](enum.Notification.html)s returned by
+/// [`new_connection_with_groups`](fn.new_connection_with_groups.html)
+pub struct Notifications {
+    messages: UnboundedReceiver<(NetlinkMessage<RtnlMessage>, SocketAddr)>,
+}
+
+impl Notifications {
+    pub(crate) fn new(
+        messages: UnboundedReceiver<(NetlinkMessage<RtnlMessage>, SocketAddr)>,
+    ) -> Self {
+        Notifications { messages }
+    }
+}
+
+impl Stream for Notifications {
+    type Item = Notification;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        loop {
+            let message = match futures::ready!(self.messages.poll_next_unpin(cx)) {
+                Some((message, _)) => message,
+                None => return Poll::Ready(None),
+            };
+            match message.payload {
+                NetlinkPayload::InnerMessage(message) => {
+                    return Poll::Ready(Some(Notification::Message(message)))
+                }
+                NetlinkPayload::Overrun(_) => return Poll::Ready(Some(Notification::Overrun)),
+                // Only notifications and overruns are expected on this channel
+                _ => continue,
+            }
+        }
+    }
+}
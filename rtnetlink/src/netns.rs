@@ -0,0 +1,55 @@
+use std::{
+    fs::File,
+    io,
+    os::unix::io::AsRawFd,
+    path::{Path, PathBuf},
+};
+
+use futures::channel::mpsc::UnboundedReceiver;
+
+use crate::{
+    new_connection,
+    packet::{NetlinkMessage, RtnlMessage},
+    Handle,
+};
+use netlink_proto::{sys::SocketAddr, Connection};
+
+/// Directory where `ip netns` bind mounts the named network namespaces
+pub const NETNS_RUN_DIR: &str = "/var/run/netns";
+
+/// Return the path of the network namespace named `name`, as created by `ip netns add NAME`.
+pub fn netns_path(name: &str) -> PathBuf {
+    Path::new(NETNS_RUN_DIR).join(name)
+}
+
+/// Create a new rtnetlink connection that operates inside the network namespace at `path`. The
+/// current thread only enters the namespace while the netlink socket is created, and moves back
+/// to its original namespace before returning, so a single process can hold connections to many
+/// namespaces at once.
+#[allow(clippy::type_complexity)]
+pub fn new_connection_in_netns<P: AsRef<Path>>(
+    path: P,
+) -> io::Result<(
+    Connection<RtnlMessage>,
+    Handle,
+    UnboundedReceiver<(NetlinkMessage<RtnlMessage>, SocketAddr)>,
+)> {
+    // setns(2) only affects the calling thread, so we must save the namespace of this thread,
+    // not the one of the process.
+    let current = File::open("/proc/thread-self/ns/net")?;
+    let target = File::open(path)?;
+
+    setns(&target)?;
+    let connection = new_connection();
+    // If we cannot go back, the thread is stuck in the wrong namespace: report that rather than
+    // the result of the connection.
+    setns(&current)?;
+    connection
+}
+
+fn setns(file: &File) -> io::Result<()> {
+    if unsafe { libc::setns(file.as_raw_fd(), libc::CLONE_NEWNET) } < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
@@ -5,7 +5,7 @@ use netlink_packet_route::{
     constants::*, nlas::route::Nla, NetlinkMessage, NetlinkPayload, RouteMessage, RtnlMessage,
 };
 
-use crate::{Error, ErrorKind, Handle};
+use crate::{BatchMessage, Error, ErrorKind, Handle};
 
 /// A request to create a new route. This is equivalent to the `ip route add` commands.
 struct RouteAddRequest {
@@ -75,8 +75,7 @@ impl RouteAddRequest {
             mut handle,
             message,
         } = self;
-        let mut req = NetlinkMessage::from(RtnlMessage::NewRoute(message));
-        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE;
+        let req = add_route_message(message);
 
         let mut response = handle.request(req)?;
         while let Some(message) = response.next().await {
@@ -266,3 +265,21 @@ impl RouteAddIpv6Request {
         self.0.message_mut()
     }
 }
+
+impl From<RouteAddIpv4Request> for BatchMessage {
+    fn from(request: RouteAddIpv4Request) -> Self {
+        BatchMessage::new(add_route_message(request.0.message))
+    }
+}
+
+impl From<RouteAddIpv6Request> for BatchMessage {
+    fn from(request: RouteAddIpv6Request) -> Self {
+        BatchMessage::new(add_route_message(request.0.message))
+    }
+}
+
+fn add_route_message(message: RouteMessage) -> NetlinkMessage<RtnlMessage> {
+    let mut req = NetlinkMessage::from(RtnlMessage::NewRoute(message));
+    req.header.flags = NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE;
+    req
+}
@@ -2,7 +2,7 @@ use futures::stream::StreamExt;
 
 use crate::{
     packet::{NetlinkMessage, NetlinkPayload, RouteMessage, RtnlMessage, NLM_F_ACK, NLM_F_REQUEST},
-    Error, ErrorKind, Handle,
+    BatchMessage, Error, ErrorKind, Handle,
 };
 
 pub struct RouteDelRequest {
@@ -21,9 +21,7 @@ impl RouteDelRequest {
             mut handle,
             message,
         } = self;
-
-        let mut req = NetlinkMessage::from(RtnlMessage::DelRoute(message));
-        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+        let req = del_route_message(message);
         let mut response = handle.request(req)?;
         while let Some(msg) = response.next().await {
             if let NetlinkPayload::Error(e) = msg.payload {
@@ -37,3 +35,15 @@ impl RouteDelRequest {
         &mut self.message
     }
 }
+
+impl From<RouteDelRequest> for BatchMessage {
+    fn from(request: RouteDelRequest) -> Self {
+        BatchMessage::new(del_route_message(request.message))
+    }
+}
+
+fn del_route_message(message: RouteMessage) -> NetlinkMessage<RtnlMessage> {
+    let mut req = NetlinkMessage::from(RtnlMessage::DelRoute(message));
+    req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+    req
+}
@@ -1,11 +1,11 @@
 use futures::{
     future::{self, Either},
-    stream::{StreamExt, TryStream},
+    stream::{StreamExt, TryStream, TryStreamExt},
     FutureExt,
 };
 
 use netlink_packet_route::{
-    constants::*, NetlinkMessage, NetlinkPayload, RouteMessage, RtnlMessage,
+    constants::*, nlas::route::Nla, NetlinkMessage, NetlinkPayload, RouteMessage, RtnlMessage,
 };
 
 use crate::{Error, ErrorKind, Handle};
@@ -13,6 +13,7 @@ use crate::{Error, ErrorKind, Handle};
 pub struct RouteGetRequest {
     handle: Handle,
     message: RouteMessage,
+    filter_builder: RouteFilterBuilder,
 }
 
 /// Internet Protocol (IP) version.
@@ -47,7 +48,11 @@ impl RouteGetRequest {
         message.header.table = RT_TABLE_UNSPEC;
         message.header.protocol = RTPROT_UNSPEC;
 
-        RouteGetRequest { handle, message }
+        RouteGetRequest {
+            handle,
+            message,
+            filter_builder: RouteFilterBuilder::new(),
+        }
     }
 
     pub fn message_mut(&mut self) -> &mut RouteMessage {
@@ -58,23 +63,113 @@ impl RouteGetRequest {
         let RouteGetRequest {
             mut handle,
             message,
+            filter_builder,
         } = self;
 
         let mut req = NetlinkMessage::from(RtnlMessage::GetRoute(message));
         req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
 
+        let filter = filter_builder.build();
         match handle.request(req) {
-            Ok(response) => Either::Left(response.map(move |msg| {
-                let (header, payload) = msg.into_parts();
-                match payload {
-                    NetlinkPayload::InnerMessage(RtnlMessage::NewRoute(msg)) => Ok(msg),
-                    NetlinkPayload::Error(err) => Err(ErrorKind::NetlinkError(err).into()),
-                    _ => Err(
-                        ErrorKind::UnexpectedMessage(NetlinkMessage::new(header, payload)).into(),
-                    ),
-                }
-            })),
+            Ok(response) => Either::Left(
+                response
+                    .map(move |msg| {
+                        let (header, payload) = msg.into_parts();
+                        match payload {
+                            NetlinkPayload::InnerMessage(RtnlMessage::NewRoute(msg)) => Ok(msg),
+                            NetlinkPayload::Error(err) => Err(ErrorKind::NetlinkError(err).into()),
+                            _ => Err(ErrorKind::UnexpectedMessage(NetlinkMessage::new(
+                                header, payload,
+                            ))
+                            .into()),
+                        }
+                    })
+                    .try_filter(move |msg| future::ready(filter(msg))),
+            ),
             Err(e) => Either::Right(future::err::<RouteMessage, Error>(e).into_stream()),
         }
     }
+
+    /// Only return the routes of the given routing table.
+    pub fn match_table(mut self, table: u32) -> Self {
+        self.message.nlas.push(Nla::Table(table));
+        self.filter_builder.table = Some(table);
+        self
+    }
+
+    /// Only return the routes installed by the given protocol (`RTPROT_*`).
+    pub fn match_protocol(mut self, protocol: u8) -> Self {
+        self.message.header.protocol = protocol;
+        self.filter_builder.protocol = Some(protocol);
+        self
+    }
+
+    /// Only return the routes of the given type (`RTN_*`).
+    pub fn match_kind(mut self, kind: u8) -> Self {
+        self.message.header.kind = kind;
+        self.filter_builder.kind = Some(kind);
+        self
+    }
+
+    /// Only return the routes going through the interface with the given index.
+    pub fn match_output_interface(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::Oif(index));
+        self.filter_builder.output_interface = Some(index);
+        self
+    }
+}
+
+// Without strict checking (see `Socket::set_strict_checking`), the
+// kernel ignores the header fields and attributes of route dump
+// requests, so the filters are also applied here.
+#[derive(Default)]
+struct RouteFilterBuilder {
+    table: Option<u32>,
+    protocol: Option<u8>,
+    kind: Option<u8>,
+    output_interface: Option<u32>,
+}
+
+impl RouteFilterBuilder {
+    fn new() -> Self {
+        Default::default()
+    }
+
+    fn build(self) -> impl Fn(&RouteMessage) -> bool {
+        move |msg: &RouteMessage| {
+            if let Some(table) = self.table {
+                let route_table = msg
+                    .nlas
+                    .iter()
+                    .find_map(|nla| match nla {
+                        Nla::Table(t) => Some(*t),
+                        _ => None,
+                    })
+                    .unwrap_or_else(|| u32::from(msg.header.table));
+                if route_table != table {
+                    return false;
+                }
+            }
+            if let Some(protocol) = self.protocol {
+                if msg.header.protocol != protocol {
+                    return false;
+                }
+            }
+            if let Some(kind) = self.kind {
+                if msg.header.kind != kind {
+                    return false;
+                }
+            }
+            if let Some(index) = self.output_interface {
+                let matches = msg.nlas.iter().any(|nla| match nla {
+                    Nla::Oif(i) => *i == index,
+                    _ => false,
+                });
+                if !matches {
+                    return false;
+                }
+            }
+            true
+        }
+    }
 }
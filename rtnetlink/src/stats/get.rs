@@ -0,0 +1,119 @@
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream},
+    FutureExt,
+};
+
+use crate::{
+    packet::{
+        NetlinkMessage, NetlinkPayload, RtnlMessage, StatsMessage, IFLA_STATS_FILTER_AF_SPEC,
+        IFLA_STATS_FILTER_LINK_64, IFLA_STATS_FILTER_LINK_OFFLOAD_XSTATS,
+        IFLA_STATS_FILTER_LINK_XSTATS, IFLA_STATS_FILTER_LINK_XSTATS_SLAVE, NLM_F_DUMP,
+        NLM_F_REQUEST,
+    },
+    Error, ErrorKind, Handle,
+};
+
+/// A request to retrieve link statistics with `RTM_GETSTATS`. This is much cheaper than a full
+/// link dump, since the kernel only reports the attributes selected by the filter mask.
+///
+/// If no attribute is explicitly selected, only the 64 bits link counters
+/// (`IFLA_STATS_LINK_64`) are requested.
+pub struct StatsGetRequest {
+    handle: Handle,
+    message: StatsMessage,
+    // Same as for `LinkGetRequest`: if `dump` is `true`, the statistics of all the links are
+    // fetched. Otherwise, only the statistics of the link that match the given index are fetched.
+    dump: bool,
+}
+
+impl StatsGetRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        StatsGetRequest {
+            handle,
+            message: StatsMessage::default(),
+            dump: true,
+        }
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl TryStream<Ok = StatsMessage, Error = Error> {
+        let StatsGetRequest {
+            mut handle,
+            mut message,
+            dump,
+        } = self;
+
+        if message.header.filter_mask == 0 {
+            message.header.filter_mask = IFLA_STATS_FILTER_LINK_64;
+        }
+
+        let mut req = NetlinkMessage::from(RtnlMessage::GetStats(message));
+
+        if dump {
+            req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+        } else {
+            req.header.flags = NLM_F_REQUEST;
+        }
+
+        match handle.request(req) {
+            Ok(response) => Either::Left(response.map(move |msg| {
+                let (header, payload) = msg.into_parts();
+                match payload {
+                    NetlinkPayload::InnerMessage(RtnlMessage::NewStats(msg)) => Ok(msg),
+                    NetlinkPayload::Error(err) => Err(ErrorKind::NetlinkError(err).into()),
+                    _ => Err(
+                        ErrorKind::UnexpectedMessage(NetlinkMessage::new(header, payload)).into(),
+                    ),
+                }
+            })),
+            Err(e) => Either::Right(future::err::<StatsMessage, Error>(e).into_stream()),
+        }
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut StatsMessage {
+        &mut self.message
+    }
+
+    /// Only retrieve the statistics of the link with the given index
+    pub fn match_index(mut self, index: u32) -> Self {
+        self.dump = false;
+        self.message.header.index = index;
+        self
+    }
+
+    /// Add the given `IFLA_STATS_FILTER_*` flags to the filter mask of the request
+    pub fn filter_mask(mut self, mask: u32) -> Self {
+        self.message.header.filter_mask |= mask;
+        self
+    }
+
+    /// Request the 64 bits link counters (`IFLA_STATS_LINK_64`)
+    pub fn link_64(self) -> Self {
+        self.filter_mask(IFLA_STATS_FILTER_LINK_64)
+    }
+
+    /// Request the link type specific statistics, for instance for bridges and bonds
+    /// (`IFLA_STATS_LINK_XSTATS`)
+    pub fn link_xstats(self) -> Self {
+        self.filter_mask(IFLA_STATS_FILTER_LINK_XSTATS)
+    }
+
+    /// Request the statistics specific to the type of the link master, for instance for bridge
+    /// ports (`IFLA_STATS_LINK_XSTATS_SLAVE`)
+    pub fn link_xstats_slave(self) -> Self {
+        self.filter_mask(IFLA_STATS_FILTER_LINK_XSTATS_SLAVE)
+    }
+
+    /// Request the hardware offload statistics (`IFLA_STATS_LINK_OFFLOAD_XSTATS`)
+    pub fn offload_xstats(self) -> Self {
+        self.filter_mask(IFLA_STATS_FILTER_LINK_OFFLOAD_XSTATS)
+    }
+
+    /// Request the per address family statistics, for instance MPLS statistics
+    /// (`IFLA_STATS_AF_SPEC`)
+    pub fn af_spec(self) -> Self {
+        self.filter_mask(IFLA_STATS_FILTER_AF_SPEC)
+    }
+}
@@ -0,0 +1,15 @@
+use super::StatsGetRequest;
+use crate::Handle;
+
+pub struct StatsHandle(Handle);
+
+impl StatsHandle {
+    pub fn new(handle: Handle) -> Self {
+        StatsHandle(handle)
+    }
+
+    /// Retrieve the statistics of the links (equivalent to `ip stats show`)
+    pub fn get(&self) -> StatsGetRequest {
+        StatsGetRequest::new(self.0.clone())
+    }
+}
@@ -0,0 +1,372 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    packet::{
+        nlas::{address, neighbour, route},
+        AddressMessage, LinkMessage, NeighbourMessage, RouteFlags, RouteMessage, RtnlMessage,
+        AF_UNSPEC,
+    },
+    Resource, WatchEvent,
+};
+
+// Addresses are identified by their link, family, prefix length, and local and peer addresses.
+type AddressKey = (u32, u8, u8, Vec<u8>, Vec<u8>);
+// Routes are identified by their family, table, destination, TOS and priority.
+type RouteKey = (u8, u32, u8, Vec<u8>, u8, u32);
+// Neighbours are identified by their link, family and destination address.
+type NeighbourKey = (u32, u8, Vec<u8>);
+
+/// The state of the resources tracked by a watcher
+#[derive(Debug, Default)]
+pub(crate) struct Cache {
+    pub links: BTreeMap<u32, LinkMessage>,
+    pub addresses: BTreeMap<AddressKey, AddressMessage>,
+    pub routes: BTreeMap<RouteKey, RouteMessage>,
+    pub neighbours: BTreeMap<NeighbourKey, NeighbourMessage>,
+}
+
+fn address_key(msg: &AddressMessage) -> AddressKey {
+    let mut local = vec![];
+    let mut peer = vec![];
+    for nla in msg.nlas.iter() {
+        match nla {
+            address::Nla::Local(bytes) => local = bytes.clone(),
+            address::Nla::Address(bytes) => peer = bytes.clone(),
+            _ => {}
+        }
+    }
+    (
+        msg.header.index,
+        msg.header.family,
+        msg.header.prefix_len,
+        local,
+        peer,
+    )
+}
+
+fn route_key(msg: &RouteMessage) -> RouteKey {
+    let mut table = u32::from(msg.header.table);
+    let mut destination = vec![];
+    let mut priority = 0;
+    for nla in msg.nlas.iter() {
+        match nla {
+            route::Nla::Table(value) => table = *value,
+            route::Nla::Destination(bytes) => destination = bytes.clone(),
+            route::Nla::Priority(value) => priority = *value,
+            _ => {}
+        }
+    }
+    (
+        msg.header.address_family,
+        table,
+        msg.header.destination_prefix_length,
+        destination,
+        msg.header.tos,
+        priority,
+    )
+}
+
+fn route_oif(msg: &RouteMessage) -> Option<u32> {
+    msg.nlas.iter().find_map(|nla| match nla {
+        route::Nla::Oif(index) => Some(*index),
+        _ => None,
+    })
+}
+
+fn neighbour_key(msg: &NeighbourMessage) -> NeighbourKey {
+    let destination = msg
+        .nlas
+        .iter()
+        .find_map(|nla| match nla {
+            neighbour::Nla::Destination(bytes) => Some(bytes.clone()),
+            _ => None,
+        })
+        .unwrap_or_default();
+    (msg.header.ifindex, msg.header.family, destination)
+}
+
+// Insert `value` in `map`, and return the corresponding event, if the resource is new or changed.
+fn insert<K: Ord, V: PartialEq + Clone>(
+    map: &mut BTreeMap<K, V>,
+    key: K,
+    value: V,
+    resource: fn(V) -> Resource,
+) -> Option<WatchEvent> {
+    match map.insert(key, value.clone()) {
+        None => Some(WatchEvent::Added(resource(value))),
+        Some(ref old) if *old == value => None,
+        Some(_) => Some(WatchEvent::Changed(resource(value))),
+    }
+}
+
+impl Cache {
+    /// Update the cache with a notification received from the kernel, and return the
+    /// corresponding events.
+    pub fn apply(&mut self, message: RtnlMessage) -> Vec<WatchEvent> {
+        use self::RtnlMessage::*;
+        let mut events = vec![];
+        match message {
+            // Bridges send link messages with the AF_BRIDGE family about their ports. In
+            // particular, a RTM_DELLINK message with this family means that a port left the
+            // bridge, not that the link was removed.
+            NewLink(msg) | DelLink(msg) if u16::from(msg.header.interface_family) != AF_UNSPEC => {}
+            NewLink(msg) => {
+                events.extend(insert(
+                    &mut self.links,
+                    msg.header.index,
+                    msg,
+                    Resource::Link,
+                ));
+            }
+            DelLink(msg) => {
+                let index = msg.header.index;
+                if let Some(link) = self.links.remove(&index) {
+                    events.push(WatchEvent::Removed(Resource::Link(link)));
+                }
+                // The kernel does not always send notifications for the resources that are
+                // removed along with a link (IPv4 routes for instance), so we purge them here.
+                events.extend(self.remove_link_resources(index));
+            }
+            NewAddress(msg) => {
+                events.extend(insert(
+                    &mut self.addresses,
+                    address_key(&msg),
+                    msg,
+                    Resource::Address,
+                ));
+            }
+            DelAddress(msg) => {
+                if let Some(address) = self.addresses.remove(&address_key(&msg)) {
+                    events.push(WatchEvent::Removed(Resource::Address(address)));
+                }
+            }
+            // Cloned routes are entries of the route cache, which are not dumped
+            NewRoute(msg) if msg.header.flags.contains(RouteFlags::RTM_F_CLONED) => {}
+            NewRoute(msg) => {
+                events.extend(insert(
+                    &mut self.routes,
+                    route_key(&msg),
+                    msg,
+                    Resource::Route,
+                ));
+            }
+            DelRoute(msg) => {
+                if let Some(route) = self.routes.remove(&route_key(&msg)) {
+                    events.push(WatchEvent::Removed(Resource::Route(route)));
+                }
+            }
+            NewNeighbour(msg) => {
+                events.extend(insert(
+                    &mut self.neighbours,
+                    neighbour_key(&msg),
+                    msg,
+                    Resource::Neighbour,
+                ));
+            }
+            DelNeighbour(msg) => {
+                if let Some(neighbour) = self.neighbours.remove(&neighbour_key(&msg)) {
+                    events.push(WatchEvent::Removed(Resource::Neighbour(neighbour)));
+                }
+            }
+            _ => {}
+        }
+        events
+    }
+
+    /// Replace the content of the cache by `other`, and return the events that describe the
+    /// differences between the two.
+    pub fn replace(&mut self, other: Cache) -> Vec<WatchEvent> {
+        let mut events = vec![];
+        diff(&self.links, &other.links, Resource::Link, &mut events);
+        diff(
+            &self.addresses,
+            &other.addresses,
+            Resource::Address,
+            &mut events,
+        );
+        diff(&self.routes, &other.routes, Resource::Route, &mut events);
+        diff(
+            &self.neighbours,
+            &other.neighbours,
+            Resource::Neighbour,
+            &mut events,
+        );
+        *self = other;
+        events
+    }
+
+    fn remove_link_resources(&mut self, index: u32) -> Vec<WatchEvent> {
+        let mut events = vec![];
+        let addresses: Vec<_> = self
+            .addresses
+            .keys()
+            .filter(|key| key.0 == index)
+            .cloned()
+            .collect();
+        for key in addresses {
+            let address = self.addresses.remove(&key).unwrap();
+            events.push(WatchEvent::Removed(Resource::Address(address)));
+        }
+        let routes: Vec<_> = self
+            .routes
+            .iter()
+            .filter(|(_, route)| route_oif(route) == Some(index))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in routes {
+            let route = self.routes.remove(&key).unwrap();
+            events.push(WatchEvent::Removed(Resource::Route(route)));
+        }
+        let neighbours: Vec<_> = self
+            .neighbours
+            .keys()
+            .filter(|key| key.0 == index)
+            .cloned()
+            .collect();
+        for key in neighbours {
+            let neighbour = self.neighbours.remove(&key).unwrap();
+            events.push(WatchEvent::Removed(Resource::Neighbour(neighbour)));
+        }
+        events
+    }
+}
+
+fn diff<K: Ord, V: PartialEq + Clone>(
+    old: &BTreeMap<K, V>,
+    new: &BTreeMap<K, V>,
+    resource: fn(V) -> Resource,
+    events: &mut Vec<WatchEvent>,
+) {
+    for (key, value) in old.iter() {
+        if !new.contains_key(key) {
+            events.push(WatchEvent::Removed(resource(value.clone())));
+        }
+    }
+    for (key, value) in new.iter() {
+        match old.get(key) {
+            None => events.push(WatchEvent::Added(resource(value.clone()))),
+            Some(old_value) if old_value != value => {
+                events.push(WatchEvent::Changed(resource(value.clone())))
+            }
+            Some(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::packet::{AF_BRIDGE, AF_INET};
+
+    fn link(index: u32, mtu: u32) -> LinkMessage {
+        let mut msg = LinkMessage::default();
+        msg.header.index = index;
+        msg.nlas.push(crate::packet::nlas::link::Nla::Mtu(mtu));
+        msg
+    }
+
+    fn address(index: u32, local: [u8; 4]) -> AddressMessage {
+        let mut msg = AddressMessage::default();
+        msg.header.family = AF_INET as u8;
+        msg.header.prefix_len = 24;
+        msg.header.index = index;
+        msg.nlas.push(address::Nla::Local(local.to_vec()));
+        msg
+    }
+
+    fn route(oif: u32, destination: [u8; 4]) -> RouteMessage {
+        let mut msg = RouteMessage::default();
+        msg.header.address_family = AF_INET as u8;
+        msg.header.table = 254;
+        msg.header.destination_prefix_length = 24;
+        msg.nlas.push(route::Nla::Destination(destination.to_vec()));
+        msg.nlas.push(route::Nla::Oif(oif));
+        msg
+    }
+
+    #[test]
+    fn link_events() {
+        let mut cache = Cache::default();
+        assert_eq!(
+            cache.apply(RtnlMessage::NewLink(link(2, 1500))),
+            vec![WatchEvent::Added(Resource::Link(link(2, 1500)))]
+        );
+        // notifications that do not change anything do not yield events
+        assert!(cache.apply(RtnlMessage::NewLink(link(2, 1500))).is_empty());
+        assert_eq!(
+            cache.apply(RtnlMessage::NewLink(link(2, 9000))),
+            vec![WatchEvent::Changed(Resource::Link(link(2, 9000)))]
+        );
+        assert_eq!(
+            cache.apply(RtnlMessage::DelLink(link(2, 0))),
+            vec![WatchEvent::Removed(Resource::Link(link(2, 9000)))]
+        );
+        assert!(cache.links.is_empty());
+        assert!(cache.apply(RtnlMessage::DelLink(link(2, 0))).is_empty());
+    }
+
+    #[test]
+    fn address_and_route_events() {
+        let mut cache = Cache::default();
+        let addr = address(2, [10, 0, 0, 1]);
+        assert_eq!(
+            cache.apply(RtnlMessage::NewAddress(addr.clone())),
+            vec![WatchEvent::Added(Resource::Address(addr.clone()))]
+        );
+        assert_eq!(
+            cache.apply(RtnlMessage::DelAddress(addr.clone())),
+            vec![WatchEvent::Removed(Resource::Address(addr))]
+        );
+
+        let rt = route(2, [10, 0, 0, 0]);
+        assert_eq!(
+            cache.apply(RtnlMessage::NewRoute(rt.clone())),
+            vec![WatchEvent::Added(Resource::Route(rt.clone()))]
+        );
+        // the cloned routes of the route cache are ignored
+        let mut cloned = route(2, [10, 0, 1, 0]);
+        cloned.header.flags = RouteFlags::RTM_F_CLONED;
+        assert!(cache.apply(RtnlMessage::NewRoute(cloned)).is_empty());
+        assert_eq!(
+            cache.apply(RtnlMessage::DelRoute(rt.clone())),
+            vec![WatchEvent::Removed(Resource::Route(rt))]
+        );
+        assert!(cache.routes.is_empty());
+    }
+
+    #[test]
+    fn del_link_purges_link_resources() {
+        let mut cache = Cache::default();
+        cache.apply(RtnlMessage::NewLink(link(2, 1500)));
+        cache.apply(RtnlMessage::NewLink(link(3, 1500)));
+        cache.apply(RtnlMessage::NewAddress(address(2, [10, 0, 0, 1])));
+        cache.apply(RtnlMessage::NewAddress(address(3, [10, 0, 1, 1])));
+        cache.apply(RtnlMessage::NewRoute(route(2, [10, 0, 0, 0])));
+        cache.apply(RtnlMessage::NewRoute(route(3, [10, 0, 1, 0])));
+
+        assert_eq!(
+            cache.apply(RtnlMessage::DelLink(link(2, 1500))),
+            vec![
+                WatchEvent::Removed(Resource::Link(link(2, 1500))),
+                WatchEvent::Removed(Resource::Address(address(2, [10, 0, 0, 1]))),
+                WatchEvent::Removed(Resource::Route(route(2, [10, 0, 0, 0]))),
+            ]
+        );
+        assert_eq!(cache.links.len(), 1);
+        assert_eq!(cache.addresses.len(), 1);
+        assert_eq!(cache.routes.len(), 1);
+    }
+
+    #[test]
+    fn skip_bridge_link_messages() {
+        let mut cache = Cache::default();
+        cache.apply(RtnlMessage::NewLink(link(2, 1500)));
+
+        let mut port = link(2, 1500);
+        port.header.interface_family = AF_BRIDGE as u8;
+        assert!(cache.apply(RtnlMessage::NewLink(port.clone())).is_empty());
+        // a port leaving its bridge does not mean the link was removed
+        assert!(cache.apply(RtnlMessage::DelLink(port)).is_empty());
+        assert_eq!(cache.links.get(&2), Some(&link(2, 1500)));
+    }
+}
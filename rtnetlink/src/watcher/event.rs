@@ -0,0 +1,21 @@
+use crate::packet::{AddressMessage, LinkMessage, NeighbourMessage, RouteMessage};
+
+/// A networking resource tracked by a [`Watcher`](struct.Watcher.html)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Resource {
+    Link(LinkMessage),
+    Address(AddressMessage),
+    Route(RouteMessage),
+    Neighbour(NeighbourMessage),
+}
+
+/// A change of the state tracked by a [`Watcher`](struct.Watcher.html)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum WatchEvent {
+    /// A new resource appeared
+    Added(Resource),
+    /// An existing resource changed. This contains the new state of the resource.
+    Changed(Resource),
+    /// A resource disappeared. This contains the last known state of the resource.
+    Removed(Resource),
+}
@@ -0,0 +1,7 @@
+mod event;
+pub use self::event::*;
+
+mod cache;
+
+mod watcher;
+pub use self::watcher::*;
@@ -0,0 +1,257 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use futures::{
+    future::{select, Either},
+    stream::{self, Stream, StreamExt},
+};
+use log::warn;
+use netlink_proto::Connection;
+
+use super::cache::Cache;
+use crate::{
+    new_connection_with_groups,
+    packet::{AddressMessage, LinkMessage, NeighbourMessage, RouteMessage, RtnlMessage},
+    Error, ErrorKind, MulticastGroup, Notification, Notifications, WatchEvent,
+};
+
+/// The kinds of resources a [`Watcher`](struct.Watcher.html) can track
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ResourceKind {
+    Link,
+    Address,
+    Route,
+    Neighbour,
+}
+
+impl ResourceKind {
+    fn groups(self) -> &'static [MulticastGroup] {
+        use self::ResourceKind::*;
+        match self {
+            Link => &[MulticastGroup::Link],
+            Address => &[MulticastGroup::Ipv4Ifaddr, MulticastGroup::Ipv6Ifaddr],
+            Route => &[MulticastGroup::Ipv4Route, MulticastGroup::Ipv6Route],
+            Neighbour => &[MulticastGroup::Neigh],
+        }
+    }
+
+    fn dump_request(self) -> RtnlMessage {
+        use self::ResourceKind::*;
+        match self {
+            Link => RtnlMessage::GetLink(LinkMessage::default()),
+            Address => RtnlMessage::GetAddress(AddressMessage::default()),
+            Route => RtnlMessage::GetRoute(RouteMessage::default()),
+            Neighbour => RtnlMessage::GetNeighbour(NeighbourMessage::default()),
+        }
+    }
+}
+
+/// How many times an interrupted dump is restarted before giving up
+const DUMP_MAX_RETRIES: usize = 5;
+
+/// Whether a request failed because the socket receive buffer overflowed
+fn is_overrun(error: &Error) -> bool {
+    match error.kind() {
+        ErrorKind::NetlinkError(e) => e.code == -libc::ENOBUFS,
+        _ => false,
+    }
+}
+
+/// A stream of [`WatchEvent`](enum.WatchEvent.html)s that tracks the state of links,
+/// addresses, routes and neighbours.
+///
+/// The watcher opens its own netlink connection, subscribed to the multicast groups of the
+/// resources it tracks, and dumps their current state: the first events it yields are
+/// `WatchEvent::Added` events for the existing resources. It then applies the notifications sent
+/// by the kernel to an in-memory cache, that can be queried at any time.
+///
+/// If notifications are lost because the socket receive buffer overflowed (`ENOBUFS`), the
+/// watcher dumps the state again and yields the events that describe the differences with its
+/// cache. An overflow also fails the dump in progress on the watcher connection, if any: such a
+/// dump is restarted on a new connection, up to 5 times. If the dump fails otherwise, or if the
+/// connection is closed while dumping, the watcher yields the error and the stream ends.
+///
+/// The watcher drives its connection itself, so it does not need to be spawned: the stream just
+/// needs to be polled.
+pub struct Watcher {
+    cache: Arc<Mutex<Cache>>,
+    events: Pin<Box<dyn Stream<Item = Result<WatchEvent, Error>> + Send>>,
+}
+
+impl Watcher {
+    /// Create a watcher that tracks the given kinds of resources
+    pub fn new(kinds: &[ResourceKind]) -> Self {
+        let cache = Arc::new(Mutex::new(Cache::default()));
+        let state = WatcherState {
+            kinds: kinds.to_vec(),
+            cache: cache.clone(),
+            connection: None,
+            events: VecDeque::new(),
+            done: false,
+        };
+        Watcher {
+            cache,
+            events: Box::pin(stream::unfold(state, WatcherState::next)),
+        }
+    }
+
+    /// Return the links currently known to the watcher
+    pub fn links(&self) -> Vec<LinkMessage> {
+        self.cache.lock().unwrap().links.values().cloned().collect()
+    }
+
+    /// Return the link with the given index, if it is known to the watcher
+    pub fn link(&self, index: u32) -> Option<LinkMessage> {
+        self.cache.lock().unwrap().links.get(&index).cloned()
+    }
+
+    /// Return the addresses currently known to the watcher
+    pub fn addresses(&self) -> Vec<AddressMessage> {
+        self.cache
+            .lock()
+            .unwrap()
+            .addresses
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    /// Return the routes currently known to the watcher
+    pub fn routes(&self) -> Vec<RouteMessage> {
+        self.cache
+            .lock()
+            .unwrap()
+            .routes
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    /// Return the neighbours currently known to the watcher
+    pub fn neighbours(&self) -> Vec<NeighbourMessage> {
+        self.cache
+            .lock()
+            .unwrap()
+            .neighbours
+            .values()
+            .cloned()
+            .collect()
+    }
+}
+
+impl Stream for Watcher {
+    type Item = Result<WatchEvent, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        self.events.as_mut().poll_next(cx)
+    }
+}
+
+struct WatcherState {
+    kinds: Vec<ResourceKind>,
+    cache: Arc<Mutex<Cache>>,
+    connection: Option<(Connection<RtnlMessage>, Notifications)>,
+    // events that have not been yielded yet
+    events: VecDeque<WatchEvent>,
+    done: bool,
+}
+
+impl WatcherState {
+    async fn next(mut self) -> Option<(Result<WatchEvent, Error>, Self)> {
+        loop {
+            if let Some(event) = self.events.pop_front() {
+                return Some((Ok(event), self));
+            }
+            if self.done {
+                return None;
+            }
+
+            let (mut connection, mut messages) = match self.connection.take() {
+                Some(connection) => connection,
+                None => {
+                    if let Err(e) = self.resync().await {
+                        self.done = true;
+                        return Some((Err(e), self));
+                    }
+                    continue;
+                }
+            };
+
+            // The connection future only completes when the socket is closed. In that case, as
+            // well as when the socket receive buffer overflowed, the connection is dropped and
+            // the next iteration resynchronizes the cache on a new one.
+            let message = match select(&mut connection, messages.next()).await {
+                Either::Left(_) => None,
+                Either::Right((message, _)) => message,
+            };
+            match message {
+                Some(Notification::Overrun) => {
+                    warn!("watcher socket overrun, notifications were lost");
+                }
+                Some(Notification::Message(message)) => {
+                    let events = self.cache.lock().unwrap().apply(message);
+                    self.events.extend(events);
+                    self.connection = Some((connection, messages));
+                }
+                None => warn!("watcher connection closed, notifications may have been lost"),
+            }
+        }
+    }
+
+    /// Open a new connection and dump the state of the tracked resources, starting over if the
+    /// socket receive buffer overflows during the dump.
+    async fn resync(&mut self) -> Result<(), Error> {
+        let mut retries = 0;
+        loop {
+            match self.try_resync().await {
+                Err(ref e) if retries < DUMP_MAX_RETRIES && is_overrun(e) => {
+                    retries += 1;
+                    warn!(
+                        "watcher socket overrun during a dump, retrying ({}/{})",
+                        retries, DUMP_MAX_RETRIES
+                    );
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Open a new connection and dump the state of the tracked resources. Messages received
+    /// during the dump are queued, and applied once the cache is up to date.
+    async fn try_resync(&mut self) -> Result<(), Error> {
+        let mut groups = vec![];
+        for kind in self.kinds.iter() {
+            groups.extend_from_slice(kind.groups());
+        }
+        let (mut connection, mut handle, messages) = new_connection_with_groups(&groups)
+            .map_err(|e| ErrorKind::ConnectionFailed(e.to_string()))?;
+
+        let mut cache = Cache::default();
+        for kind in self.kinds.iter() {
+            // Only one dump can be in progress on a socket at a time
+            let dump = Box::pin(handle.dump(kind.dump_request(), DUMP_MAX_RETRIES));
+            let dumped = match select(&mut connection, dump).await {
+                // Retrying right away could spin forever if the socket keeps failing, so give up
+                Either::Left(_) => {
+                    return Err(ErrorKind::ConnectionFailed(
+                        "the watcher connection was closed during a dump".into(),
+                    )
+                    .into());
+                }
+                Either::Right((dumped, _)) => dumped?,
+            };
+            for message in dumped {
+                cache.apply(message);
+            }
+        }
+
+        let events = self.cache.lock().unwrap().replace(cache);
+        self.events.extend(events);
+        self.connection = Some((connection, messages));
+        Ok(())
+    }
+}